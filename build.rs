@@ -4,14 +4,33 @@ use std::fs::read_dir;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
 
-fn days(input_dir: &str) -> io::Result<Vec<u32>> {
+/// The day of an input file, named `NN.txt` or `NN-name.txt`.
+fn input_day(file_name: &str) -> Option<u32> {
+    // same rule as `solver::parse_input_name`, which can't be shared with the build script
+    let stem = file_name.strip_suffix(".txt")?;
+    let day = match stem.split_once('-') {
+        Some((_, "")) => return None,
+        Some((day, _)) => day,
+        None => stem,
+    };
+    if day.len() != 2 {
+        return None;
+    }
+    day.parse().ok()
+}
+
+fn days(input_dir: &str, examples_dir: &str) -> io::Result<Vec<u32>> {
+    // days with only examples get a solution template too, to start with the small case
+    let examples = read_dir(examples_dir).into_iter().flatten();
     let mut days = read_dir(input_dir)?
+        .chain(examples)
         .flatten()
         .filter(|e| e.path().is_file())
         .flat_map(|e| e.file_name().into_string())
-        .flat_map(|s| s[0..2].parse::<u32>())
+        .flat_map(|s| input_day(&s))
         .collect::<Vec<_>>();
     days.sort_unstable();
+    days.dedup();
     Ok(days)
 }
 
@@ -24,21 +43,28 @@ fn gen_solutions_mod<P: AsRef<Path>>(p: P, days: &[u32]) -> io::Result<()> {
         .iter()
         .map(|s| format_ident!("day{}", s))
         .collect::<Vec<_>>();
+    let module_names = mods.iter().map(|m| m.to_string()).collect::<Vec<_>>();
 
     let tokens = quote! {
-        use crate::solver::{ProblemOutput, Solver};
+        use crate::solver::{Registered, Solver};
 
         #(mod #mods;)*
 
-        pub fn exec_day(day: u32) -> Option<ProblemOutput> {
-            match day {
-                #(#days => Some(#mods::Problem.solve(day)),)*
-                _ => None,
-            }
-        }
-
-        pub fn exec_all_days() -> Vec<ProblemOutput> {
-            vec![#(#mods::Problem.solve(#days)),*]
+        /// The solvers of all days, in order.
+        pub static REGISTRY: &[Registered] = &[
+            #(Registered {
+                day: #days,
+                module: #module_names,
+                title: <#mods::Problem as Solver>::TITLE,
+                tags: <#mods::Problem as Solver>::TAGS,
+                implemented: <#mods::Problem as Solver>::IMPLEMENTED,
+                solver: &#mods::Problem,
+            },)*
+        ];
+
+        /// The solver of a day, if it is registered.
+        pub fn find(day: u32) -> Option<&'static Registered> {
+            REGISTRY.iter().find(|s| s.day == day)
         }
     };
     let syntax_tree = syn::parse2(tokens).expect("valid token stream");
@@ -55,6 +81,10 @@ fn gen_solutions(dir: &str, days: &[u32]) -> io::Result<()> {
         pub struct Problem;
 
         impl Solver for Problem {
+            const TITLE: &'static str = "";
+            const TAGS: &'static [&'static str] = &[];
+            const IMPLEMENTED: bool = false;
+
             type Input = ();
             type Output1 = u64;
             type Output2 = u64;
@@ -89,10 +119,11 @@ fn gen_solutions(dir: &str, days: &[u32]) -> io::Result<()> {
 
 fn main() -> Result<(), Box<dyn Error>> {
     let input_dir = "./input";
+    let examples_dir = "./input/examples";
     let output_dir = "./src/solutions";
     let solutions_mod_output_path = Path::new(&output_dir).join("mod.rs");
 
-    let days = days(input_dir)?;
+    let days = days(input_dir, examples_dir)?;
 
     // write solutions mod file
     gen_solutions_mod(solutions_mod_output_path, &days)?;
@@ -0,0 +1,173 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator wrapper counting allocations, installed by the `count-allocs` feature.
+///
+/// Counters are global, and the start of a phase resets the peak, so phases must not overlap:
+/// parallel runs are refused, and a part still running after being abandoned on timeout skews
+/// the counts and peaks of the following phases.
+#[cfg_attr(not(feature = "count-allocs"), allow(dead_code))]
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[cfg_attr(not(feature = "count-allocs"), allow(dead_code))]
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made during a phase.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// Highest live memory reached during the phase, above what was live when it started
+    pub peak_bytes: usize,
+}
+
+impl AllocStats {
+    /// Stats of several phases together, the peak being the highest one.
+    pub fn combined<'a>(stats: impl IntoIterator<Item = &'a AllocStats>) -> Self {
+        stats.into_iter().fold(Self::default(), |acc, a| Self {
+            allocations: acc.allocations + a.allocations,
+            bytes: acc.bytes + a.bytes,
+            peak_bytes: acc.peak_bytes.max(a.peak_bytes),
+        })
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhaseAllocs {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl PhaseAllocs {
+    pub fn total(&self) -> AllocStats {
+        AllocStats::combined([&self.parse, &self.part1, &self.part2])
+    }
+}
+
+/// Counter values at the start of a phase.
+pub struct Measure {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+impl Measure {
+    pub fn start() -> Self {
+        let live = LIVE_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(live, Ordering::Relaxed);
+
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            live,
+        }
+    }
+
+    pub fn finish(self) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.bytes,
+            peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(self.live),
+        }
+    }
+}
+
+/// Whether the counting allocator is installed, otherwise all stats are zero.
+pub fn enabled() -> bool {
+    cfg!(feature = "count-allocs")
+}
+
+/// Formats a byte count with a binary unit.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,144 @@
+use crate::solver::PartResult;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Expected answers of an input, stored next to it as `NN.answers` or `NN-name.answers`, one
+/// line per part.
+/// An empty or missing line means the answer of that part is not known yet.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The answers file of an input file, e.g. `input/07-alice.answers` for `input/07-alice.txt`.
+pub fn answers_file(input: &Path) -> PathBuf {
+    input.with_extension("answers")
+}
+
+impl Expected {
+    pub fn load<P: AsRef<Path>>(p: P) -> io::Result<Option<Self>> {
+        match fs::read_to_string(p) {
+            Ok(s) => Ok(Some(Self::parse(&s))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, p: P) -> io::Result<()> {
+        fs::write(
+            p,
+            format!(
+                "{}\n{}\n",
+                self.part1.as_deref().unwrap_or_default(),
+                self.part2.as_deref().unwrap_or_default()
+            ),
+        )
+    }
+
+    fn parse(s: &str) -> Self {
+        let mut lines = s
+            .lines()
+            .map(str::trim)
+            .map(|l| (!l.is_empty()).then(|| l.to_string()));
+
+        Self {
+            part1: lines.next().flatten(),
+            part2: lines.next().flatten(),
+        }
+    }
+}
+
+pub enum Verdict<'a> {
+    Pass(&'a str),
+    Mismatch { expected: &'a str, actual: &'a str },
+    Unknown(&'a str),
+    Failed(String),
+    Skipped,
+}
+
+impl<'a> Verdict<'a> {
+    pub fn new(expected: Option<&'a str>, actual: &'a PartResult) -> Self {
+        match (expected, actual) {
+            (Some(expected), PartResult::Answer(actual)) if expected == actual => {
+                Verdict::Pass(actual)
+            }
+            (Some(expected), PartResult::Answer(actual)) => Verdict::Mismatch { expected, actual },
+            (None, PartResult::Answer(actual)) => Verdict::Unknown(actual),
+            (_, PartResult::Failed(_) | PartResult::TimedOut(_)) => {
+                Verdict::Failed(actual.error().unwrap_or_default())
+            }
+            (_, PartResult::Skipped) => Verdict::Skipped,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Failed(_))
+    }
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass(a) => write!(f, "pass ({a})"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Verdict::Unknown(a) => write!(f, "unknown ({a}, no expected answer)"),
+            Verdict::Failed(e) => write!(f, "FAIL ({e})"),
+            Verdict::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Expected::parse("123\n456\n"),
+            Expected {
+                part1: Some("123".to_string()),
+                part2: Some("456".to_string())
+            }
+        );
+        assert_eq!(
+            Expected::parse("123\n"),
+            Expected {
+                part1: Some("123".to_string()),
+                part2: None
+            }
+        );
+        assert_eq!(
+            Expected::parse("\n456"),
+            Expected {
+                part1: None,
+                part2: Some("456".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answer = PartResult::Answer("42".to_string());
+        assert!(matches!(
+            Verdict::new(Some("42"), &answer),
+            Verdict::Pass(_)
+        ));
+        assert!(Verdict::new(Some("43"), &answer).is_failure());
+        assert!(matches!(Verdict::new(None, &answer), Verdict::Unknown(_)));
+        assert!(matches!(
+            Verdict::new(Some("42"), &PartResult::Skipped),
+            Verdict::Skipped
+        ));
+        assert!(Verdict::new(Some("42"), &PartResult::Failed("oops".to_string())).is_failure());
+        assert!(
+            Verdict::new(Some("42"), &PartResult::TimedOut(Duration::from_secs(1))).is_failure()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::report::{Format, csv_field, format_short, nanos};
+use crate::solver::{InputSource, Registered, RunOptions, Timings};
+use crate::stats::Stats;
+use humantime::format_duration;
+use serde_json::{Value, json};
+use std::time::Duration;
+
+pub struct BenchResult {
+    pub day: u32,
+    /// Name of the input, for named inputs of the day
+    pub input: Option<String>,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// Failures of the first failing run, the day is not measured any further
+    pub failures: Vec<String>,
+}
+
+impl BenchResult {
+    fn title(&self) -> String {
+        match &self.input {
+            Some(name) => format!("Day {} ({name})", self.day),
+            None => format!("Day {}", self.day),
+        }
+    }
+
+    fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Benchmarks a day on each of its inputs, or on the input given in the options.
+pub fn bench_day(
+    solver: &Registered,
+    opts: &RunOptions,
+    runs: usize,
+    warmup: usize,
+) -> Vec<BenchResult> {
+    let InputSource::Default = opts.input else {
+        return vec![bench_input(solver, None, opts, runs, warmup)];
+    };
+
+    opts.day_inputs(solver.day)
+        .into_iter()
+        .map(|i| {
+            let opts = RunOptions {
+                input: InputSource::File(i.path),
+                ..opts.clone()
+            };
+            bench_input(solver, i.name, &opts, runs, warmup)
+        })
+        .collect()
+}
+
+/// Solves a day `warmup` times without measuring, then `runs` times to compute timing stats.
+fn bench_input(
+    solver: &Registered,
+    input: Option<String>,
+    opts: &RunOptions,
+    runs: usize,
+    warmup: usize,
+) -> BenchResult {
+    let mut timings: Vec<Timings> = Vec::with_capacity(runs);
+    let mut failures = vec![];
+
+    for i in 0..warmup + runs {
+        // a single input is given, hence a single output
+        let o = solver.solve(opts).pop().expect("an output for the input");
+        if o.failed() {
+            failures = o.failures();
+            break;
+        }
+        if i >= warmup {
+            timings.push(o.timings);
+        }
+    }
+
+    let stats =
+        |f: fn(&Timings) -> Duration| Stats::new(&timings.iter().map(f).collect::<Vec<_>>());
+
+    BenchResult {
+        day: solver.day,
+        input,
+        runs: timings.len(),
+        parse: stats(|t| t.parse_duration),
+        part1: stats(|t| t.part1_duration),
+        part2: stats(|t| t.part2_duration),
+        failures,
+    }
+}
+
+pub fn print(format: Format, results: &[BenchResult], warmup: usize) {
+    match format {
+        Format::Text => print_text(results, warmup),
+        Format::Json => println!("{:#}", json(results, warmup)),
+        Format::Csv => print_csv(results),
+    }
+}
+
+fn print_text(results: &[BenchResult], warmup: usize) {
+    for r in results {
+        println!("=== {} === ({} runs, {warmup} warm-up)", r.title(), r.runs);
+        if !r.failures.is_empty() {
+            for f in r.failures.iter() {
+                println!("Failed: {f}");
+            }
+            continue;
+        }
+
+        println!(
+            "{:<8} {:>12} {:>12} {:>12} {:>12}",
+            "", "min", "median", "mean", "stddev"
+        );
+        for (name, s) in [
+            ("Parsing", &r.parse),
+            ("Part 1", &r.part1),
+            ("Part 2", &r.part2),
+        ] {
+            println!(
+                "{name:<8} {:>12} {:>12} {:>12} {:>12}",
+                format_short(s.min),
+                format_short(s.median),
+                format_short(s.mean),
+                format_short(s.stddev)
+            );
+        }
+    }
+
+    let parsed = results.iter().map(|r| r.parse.median).sum::<Duration>();
+    let solved = results
+        .iter()
+        .map(|r| r.part1.median + r.part2.median)
+        .sum::<Duration>();
+
+    println!("=== Global stats (medians) ===");
+    println!("Parsing: {}", format_duration(parsed));
+    println!("Solving: {}", format_duration(solved));
+    println!("Total:   {}", format_duration(parsed + solved));
+}
+
+fn stats_json(s: &Stats) -> Value {
+    json!({
+        "min_ns": nanos(s.min),
+        "median_ns": nanos(s.median),
+        "mean_ns": nanos(s.mean),
+        "stddev_ns": nanos(s.stddev),
+    })
+}
+
+fn json(results: &[BenchResult], warmup: usize) -> Value {
+    let days = results
+        .iter()
+        .map(|r| {
+            json!({
+                "day": r.day,
+                "input": r.input,
+                "runs": r.runs,
+                "parse": stats_json(&r.parse),
+                "part1": stats_json(&r.part1),
+                "part2": stats_json(&r.part2),
+                "errors": r.failures,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({ "warmup": warmup, "days": days })
+}
+
+fn print_csv(results: &[BenchResult]) {
+    println!("day,input,phase,runs,min_ns,median_ns,mean_ns,stddev_ns,errors");
+    for r in results {
+        for (phase, s) in r.phases() {
+            println!(
+                "{},{},{phase},{},{},{},{},{},{}",
+                r.day,
+                csv_field(r.input.as_deref().unwrap_or_default()),
+                r.runs,
+                nanos(s.min),
+                nanos(s.median),
+                nanos(s.mean),
+                nanos(s.stddev),
+                csv_field(&r.failures.join("; "))
+            );
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::client::DEFAULT_BASE_URL;
+use crate::report::Format;
+use crate::solver::{InputSource, Parts, RunOptions};
+use crate::trace::Level;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const LAST_DAY: u32 = 12;
+
+#[derive(Parser)]
+#[command(version, about = "Advent of Code 2025 solutions runner")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Solve the selected days, or all days when none is given
+    Run(RunArgs),
+    /// Solve days repeatedly and report timing statistics
+    Bench(BenchArgs),
+    /// Compare the last saved benchmark with a previous one and report regressions
+    Compare {
+        /// Label or git revision of the baseline run, defaults to the previous run
+        #[arg(short, long)]
+        baseline: Option<String>,
+
+        /// Slowdown of a day, in percent, above which it is reported as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Compare the answers of the selected days, or all days, with the expected ones from input/NN.answers
+    Check {
+        /// Days to check, e.g. `7` or `1-3,5`
+        days: Option<DaySelection>,
+
+        /// Check the answers of the example inputs, from input/examples/NN.answers
+        #[arg(short, long)]
+        example: bool,
+
+        /// Record the computed answers as the expected ones
+        #[arg(long)]
+        record: bool,
+    },
+    /// Solve a day again each time its input files change
+    Watch {
+        #[arg(value_parser = day_parser())]
+        day: u32,
+
+        /// Watch and read this input file instead of input/NN.txt
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Watch and solve the example inputs instead of the real ones
+        #[arg(short, long, conflicts_with = "input")]
+        example: bool,
+
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Download the inputs of the selected days, or all released days, keeping existing inputs
+    Fetch {
+        /// Days to download, e.g. `7` or `1-3,5`
+        days: Option<DaySelection>,
+
+        /// Base URL of the Advent of Code server
+        #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Submit the answer of a part, the computed one unless given
+    Submit {
+        #[arg(value_parser = day_parser())]
+        day: u32,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit instead of solving the part on input/NN.txt
+        answer: Option<String>,
+
+        /// Base URL of the Advent of Code server
+        #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Write the examples of a saved puzzle page to input/examples, with their answers
+    Extract {
+        #[arg(value_parser = day_parser())]
+        day: u32,
+
+        /// Puzzle page saved from the browser
+        page: PathBuf,
+
+        /// Overwrite existing example files
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// List the available days with their title, tags, status and inputs
+    List,
+    /// Create the input file of a new day, the solution is generated on next build
+    New {
+        #[arg(value_parser = day_parser())]
+        day: u32,
+    },
+}
+
+/// What to solve and how to report it, shared by `run` and `bench`.
+#[derive(Args, Default)]
+pub struct SolveArgs {
+    /// Days to solve, e.g. `7` or `1-3,5`
+    pub days: Option<DaySelection>,
+
+    /// Read the input from this file instead of input/NN.txt, `-` reads from stdin
+    #[arg(short, long, requires = "days")]
+    pub input: Option<InputSource>,
+
+    /// Solve the example inputs from input/examples instead of the real ones
+    #[arg(short, long, conflicts_with = "input")]
+    pub example: bool,
+
+    /// Only solve this part, the other one is reported as skipped
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Output format of the results and timings
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Abandon parts running for longer than this, e.g. `500ms` or `10s`, parts solved together
+    /// share it
+    #[arg(short, long, value_parser = humantime::parse_duration)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Args, Default)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub solve: SolveArgs,
+
+    /// Solve all days concurrently, results are still reported in day order
+    #[arg(short = 'P', long)]
+    pub parallel: bool,
+
+    /// Print the diagnostics of the solvers of these days on stderr, e.g. `7` or `1-3,5`
+    #[arg(long)]
+    pub trace: Option<DaySelection>,
+
+    /// Most detailed diagnostics printed for the traced days
+    #[arg(long, value_enum, default_value_t, requires = "trace")]
+    pub trace_level: Level,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub solve: SolveArgs,
+
+    /// Number of measured runs per day
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+
+    /// Number of unmeasured runs per day before measuring
+    #[arg(short, long, default_value_t = 1)]
+    pub warmup: u32,
+
+    /// Save the median timings to the benchmark history
+    #[arg(short, long)]
+    pub save: bool,
+
+    /// Name of the saved run, usable as a baseline by `compare`
+    #[arg(short, long, requires = "save")]
+    pub label: Option<String>,
+}
+
+impl SolveArgs {
+    pub fn options(&self) -> RunOptions {
+        RunOptions {
+            input: self.input.clone().unwrap_or_default(),
+            examples: self.example,
+            parts: self.part.map(Parts::only).unwrap_or_default(),
+            timeout: self.timeout,
+        }
+    }
+}
+
+fn day_parser() -> clap::builder::RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(1..=LAST_DAY as i64)
+}
+
+/// A sorted set of days, written as a comma separated list of days and ranges, e.g. `1-3,5,7`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DaySelection(Vec<u32>);
+
+impl DaySelection {
+    pub fn days(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// The selected day, if exactly one day is selected.
+    pub fn single(&self) -> Option<u32> {
+        match self.0.as_slice() {
+            &[day] => Some(day),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |d: &str| match d.trim().parse::<u32>() {
+            Ok(day @ 1..=LAST_DAY) => Ok(day),
+            _ => Err(format!(
+                "invalid day `{d}`, expected a number in 1..={LAST_DAY}"
+            )),
+        };
+
+        let mut days = vec![];
+        for part in s.split(',') {
+            match part.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(format!("invalid range `{part}`"));
+                    }
+                    days.extend(start..=end);
+                }
+                None => days.push(parse_day(part)?),
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+
+        Ok(Self(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        assert_eq!(DaySelection::from_str("7").unwrap().days(), &[7]);
+        assert_eq!(
+            DaySelection::from_str("1-3,5,7").unwrap().days(),
+            &[1, 2, 3, 5, 7]
+        );
+        assert_eq!(
+            DaySelection::from_str("5,2-3,3").unwrap().days(),
+            &[2, 3, 5]
+        );
+        assert_eq!(DaySelection::from_str("4").unwrap().single(), Some(4));
+        assert_eq!(DaySelection::from_str("4-5").unwrap().single(), None);
+        assert!(DaySelection::from_str("x7").is_err());
+        assert!(DaySelection::from_str("0").is_err());
+        assert!(DaySelection::from_str("3-1").is_err());
+        assert!(DaySelection::from_str("1,,2").is_err());
+    }
+}
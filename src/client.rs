@@ -0,0 +1,258 @@
+use anyhow::{Context, anyhow, bail};
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2025;
+
+// local state of the client, ignored by git
+const SESSION_FILE: &str = ".aoc/session";
+const LAST_REQUEST_FILE: &str = ".aoc/last_request";
+
+/// Minimum delay between two requests to the server.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+/// The session token, from the `AOC_SESSION` environment variable or the `.aoc/session` file.
+pub fn session() -> anyhow::Result<String> {
+    if let Ok(s) = std::env::var("AOC_SESSION") {
+        return Ok(s.trim().to_string());
+    }
+
+    fs::read_to_string(SESSION_FILE)
+        .map(|s| s.trim().to_string())
+        .map_err(|_| {
+            anyhow!(
+                "no session token, set AOC_SESSION or write the session cookie to {SESSION_FILE}"
+            )
+        })
+}
+
+/// When the puzzle of a day is released, at midnight EST.
+pub fn unlock_time(day: u32) -> SystemTime {
+    humantime::parse_rfc3339(&format!("{YEAR}-12-{day:02}T05:00:00Z")).expect("valid date")
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    /// File recording the time of the last request, to space them out
+    throttle_file: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, throttle_file: Option<PathBuf>) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            throttle_file,
+        }
+    }
+
+    /// A client using the stored session token and spacing out its requests.
+    pub fn from_env(base_url: &str) -> anyhow::Result<Self> {
+        Ok(Self::new(
+            base_url,
+            session()?,
+            Some(PathBuf::from(LAST_REQUEST_FILE)),
+        ))
+    }
+
+    /// Waits until the minimum interval since the last request has elapsed, then records this one.
+    fn throttle(&self) -> anyhow::Result<()> {
+        let Some(file) = &self.throttle_file else {
+            return Ok(());
+        };
+
+        let last = fs::read_to_string(file)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(|ms| UNIX_EPOCH + Duration::from_millis(ms));
+        if let Some(elapsed) = last.and_then(|t| t.elapsed().ok())
+            && elapsed < MIN_REQUEST_INTERVAL
+        {
+            thread::sleep(MIN_REQUEST_INTERVAL - elapsed);
+        }
+
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        fs::write(file, now.to_string())
+            .with_context(|| format!("unable to write {}", file.display()))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, path: &str) -> anyhow::Result<String> {
+        self.throttle()?;
+
+        let url = self.url(path);
+        let response = self.agent.get(&url).header("Cookie", &self.cookie()).call();
+        Self::body(&url, response)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        self.throttle()?;
+
+        let url = self.url(path);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form(form.iter().copied());
+        Self::body(&url, response)
+    }
+
+    fn body(url: &str, response: Result<Response<Body>, ureq::Error>) -> anyhow::Result<String> {
+        let mut response = response.with_context(|| format!("request to {url} failed"))?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+
+        match status {
+            200 => Ok(body),
+            404 => bail!("{url} not found, the puzzle may not be released yet"),
+            400 | 401 | 500 if body.contains("log in") => {
+                bail!("the session token was rejected, it may have expired")
+            }
+            _ => bail!(
+                "request to {url} failed with status {status}: {}",
+                body.lines().next().unwrap_or_default()
+            ),
+        }
+    }
+
+    pub fn input(&self, day: u32) -> anyhow::Result<String> {
+        self.get(&format!("/{YEAR}/day/{day}/input"))
+    }
+
+    /// Submits the answer of a part, returning the HTML page of the response.
+    pub fn submit(&self, day: u32, part: u8, answer: &str) -> anyhow::Result<String> {
+        self.post(
+            &format!("/{YEAR}/day/{day}/answer"),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves a single response on a local port, returning the base URL and the received request.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, server)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve_once("200 OK", "1-2,3-4\n");
+        let client = Client::new(&base_url, "abc".to_string(), None);
+
+        assert_eq!(client.input(7).unwrap(), "1-2,3-4\n");
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2025/day/7/input "));
+        assert!(request.contains("cookie: session=abc"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve_once("200 OK", "<article><p>That's the right answer!</p>");
+        let client = Client::new(&base_url, "abc".to_string(), None);
+
+        assert!(client.submit(3, 2, "42").unwrap().contains("right answer"));
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("post /2025/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (base_url, _) = serve_once("404 Not Found", "");
+        let client = Client::new(&base_url, "abc".to_string(), None);
+        assert!(
+            client
+                .input(12)
+                .unwrap_err()
+                .to_string()
+                .contains("not found")
+        );
+
+        let (base_url, _) = serve_once(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = Client::new(&base_url, "expired".to_string(), None);
+        assert!(
+            client
+                .input(1)
+                .unwrap_err()
+                .to_string()
+                .contains("rejected")
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use crate::answers::Expected;
+
+/// The example of a part of a puzzle page.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct PartExample {
+    /// `<pre><code>` blocks of the part, the first one usually being the example
+    pub inputs: Vec<String>,
+    /// Last emphasized `<code><em>` value of the part, usually the answer of the example
+    pub answer: Option<String>,
+}
+
+/// An example input file to write, with the answers it is known to give.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ExampleFile {
+    /// `None` for `NN.txt`, `Some(name)` for `NN-name.txt`
+    pub name: Option<String>,
+    pub input: String,
+    pub expected: Expected,
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The contents of all the `start ... end` sections of `html`.
+fn sections<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    html.split(start)
+        .skip(1)
+        .filter_map(move |s| s.split_once(end).map(|(content, _)| content))
+}
+
+/// Extracts the example of each part from a saved puzzle page, one per `<article>`.
+pub fn parse_page(html: &str) -> Vec<PartExample> {
+    sections(html, "<article", "</article>")
+        .map(|article| PartExample {
+            inputs: sections(article, "<pre><code>", "</code></pre>")
+                .map(|block| decode_entities(&strip_tags(block)))
+                .collect(),
+            answer: sections(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|answer| decode_entities(&strip_tags(answer)).trim().to_string()),
+        })
+        .collect()
+}
+
+/// Groups the examples of both parts into files: `NN.txt` for the first block, then `NN-2.txt`,
+/// `NN-3.txt`... for each different block. The answer of a part goes with its first block, and
+/// part 2 shares the file of part 1 when it uses the same example.
+pub fn example_files(parts: &[PartExample]) -> Vec<ExampleFile> {
+    let mut files: Vec<ExampleFile> = vec![];
+    for (i, part) in parts.iter().take(2).enumerate() {
+        // part 2 often reuses the example of part 1 without repeating it
+        if part.inputs.is_empty()
+            && i == 1
+            && let Some(file) = files.first_mut()
+        {
+            file.expected.part2 = part.answer.clone();
+            continue;
+        }
+
+        for (j, input) in part.inputs.iter().enumerate() {
+            let k = match files.iter().position(|f| f.input == *input) {
+                Some(k) => k,
+                None => {
+                    files.push(ExampleFile {
+                        name: (!files.is_empty()).then(|| (files.len() + 1).to_string()),
+                        input: input.clone(),
+                        expected: Expected::default(),
+                    });
+                    files.len() - 1
+                }
+            };
+            if j > 0 {
+                continue;
+            }
+            match i {
+                0 => files[k].expected.part1 = part.answer.clone(),
+                _ => files[k].expected.part2 = part.answer.clone(),
+            }
+        }
+    }
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Cafeteria ---</h2>
+<p>For example:</p>
+<pre><code>3-5
+10-14
+
+1
+5
+</code></pre>
+<p>Ingredient <code>1</code> is spoiled, <code><em>5</em></code> is fresh.</p>
+<p>So, <code><em>1</em></code> ingredient is fresh.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a &lt;<em>b</em>&gt; &amp; c
+</code></pre>
+<p>The answer is <code><em>14</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_page() {
+        let parts = parse_page(PAGE);
+        assert_eq!(
+            parts,
+            vec![
+                PartExample {
+                    inputs: vec!["3-5\n10-14\n\n1\n5\n".to_string()],
+                    answer: Some("1".to_string()),
+                },
+                PartExample {
+                    inputs: vec!["a <b> & c\n".to_string()],
+                    answer: Some("14".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example_files() {
+        let files = example_files(&parse_page(PAGE));
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].name, None);
+        assert_eq!(files[0].expected.part1.as_deref(), Some("1"));
+        assert_eq!(files[1].name.as_deref(), Some("2"));
+        assert_eq!(files[1].expected.part2.as_deref(), Some("14"));
+
+        let shared = [
+            PartExample {
+                inputs: vec!["x\n".to_string()],
+                answer: Some("1".to_string()),
+            },
+            PartExample {
+                inputs: vec![],
+                answer: Some("2".to_string()),
+            },
+        ];
+        let files = example_files(&shared);
+        assert_eq!(files.len(), 1);
+        assert_eq!(
+            files[0].expected,
+            Expected {
+                part1: Some("1".to_string()),
+                part2: Some("2".to_string()),
+            }
+        );
+
+        let several = [
+            PartExample {
+                inputs: vec!["x\n".to_string(), "y\n".to_string()],
+                answer: Some("1".to_string()),
+            },
+            PartExample {
+                inputs: vec!["x\n".to_string(), "z\n".to_string()],
+                answer: Some("2".to_string()),
+            },
+        ];
+        let files = example_files(&several);
+        let names = files.iter().map(|f| f.name.as_deref()).collect::<Vec<_>>();
+        assert_eq!(names, [None, Some("2"), Some("3")]);
+        assert_eq!(
+            files[0].expected,
+            Expected {
+                part1: Some("1".to_string()),
+                part2: Some("2".to_string()),
+            }
+        );
+        assert_eq!(files[1].input, "y\n");
+        assert_eq!(files[1].expected, Expected::default());
+        assert_eq!(files[2].input, "z\n");
+    }
+}
@@ -0,0 +1,239 @@
+use crate::bench::BenchResult;
+use crate::report::{format_short, nanos};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_FILE: &str = "bench_history.jsonl";
+
+/// Median timings of a single day in a benchmark run.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayTimings {
+    pub day: u32,
+    /// Name of the input, for named inputs of the day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+impl DayTimings {
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns + self.part1_ns + self.part2_ns)
+    }
+}
+
+/// A benchmark run, stored as one JSON line of the history file.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HistoryEntry {
+    pub revision: String,
+    pub label: Option<String>,
+    /// Seconds since the unix epoch
+    pub timestamp: u64,
+    pub days: Vec<DayTimings>,
+}
+
+impl HistoryEntry {
+    pub fn new(results: &[BenchResult], label: Option<String>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let days = results
+            .iter()
+            .filter(|r| r.failures.is_empty())
+            .map(|r| DayTimings {
+                day: r.day,
+                input: r.input.clone(),
+                parse_ns: nanos(r.parse.median),
+                part1_ns: nanos(r.part1.median),
+                part2_ns: nanos(r.part2.median),
+            })
+            .collect();
+
+        Self {
+            revision: git_revision(),
+            label,
+            timestamp,
+            days,
+        }
+    }
+
+    /// Whether this run is named by `name`, either its label or a prefix of its revision.
+    pub fn matches(&self, name: &str) -> bool {
+        self.label.as_deref() == Some(name) || self.revision.starts_with(name)
+    }
+
+    pub fn describe(&self) -> String {
+        let date =
+            humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_secs(self.timestamp));
+        match &self.label {
+            Some(label) => format!("{label} ({}, {date})", self.revision),
+            None => format!("{} ({date})", self.revision),
+        }
+    }
+}
+
+/// The short hash of the current git revision, suffixed with `-dirty` when the tree has changes.
+fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain", "--untracked-files=no"]),
+    ) {
+        (Some(rev), Some(status)) if !status.is_empty() => format!("{rev}-dirty"),
+        (Some(rev), _) => rev,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+pub fn load() -> anyhow::Result<Vec<HistoryEntry>> {
+    let content = match fs::read_to_string(HISTORY_FILE) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e).with_context(|| format!("unable to read {HISTORY_FILE}")),
+    };
+
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .map(|(i, l)| {
+            serde_json::from_str(l)
+                .with_context(|| format!("invalid entry on line {} of {HISTORY_FILE}", i + 1))
+        })
+        .collect()
+}
+
+pub fn append(entry: &HistoryEntry) -> anyhow::Result<()> {
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE)
+        .with_context(|| format!("unable to open {HISTORY_FILE}"))?;
+    writeln!(f, "{}", serde_json::to_string(entry)?)?;
+
+    Ok(())
+}
+
+pub struct Comparison {
+    pub day: u32,
+    pub input: Option<String>,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the total time of the day, in percent.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.current.as_secs_f64() - baseline) / baseline * 100.0
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the inputs of the days present in both runs.
+pub fn compare(baseline: &HistoryEntry, current: &HistoryEntry) -> Vec<Comparison> {
+    current
+        .days
+        .iter()
+        .filter_map(|c| {
+            let b = baseline
+                .days
+                .iter()
+                .find(|b| b.day == c.day && b.input == c.input)?;
+            Some(Comparison {
+                day: c.day,
+                input: c.input.clone(),
+                baseline: b.total(),
+                current: c.total(),
+            })
+        })
+        .collect()
+}
+
+pub fn print_comparison(comparisons: &[Comparison], threshold: f64) {
+    if comparisons.is_empty() {
+        println!("No day in common between both runs");
+    }
+
+    for c in comparisons {
+        let flag = if c.regressed(threshold) {
+            "REGRESSION"
+        } else {
+            ""
+        };
+        let title = match &c.input {
+            Some(name) => format!("Day {:02} ({name})", c.day),
+            None => format!("Day {:02}", c.day),
+        };
+        println!(
+            "{title}: {:>12} -> {:>12} {:>+8.1}% {flag}",
+            format_short(c.baseline),
+            format_short(c.current),
+            c.change()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(revision: &str, label: Option<&str>, totals: &[(u32, u64)]) -> HistoryEntry {
+        HistoryEntry {
+            revision: revision.to_string(),
+            label: label.map(str::to_string),
+            timestamp: 0,
+            days: totals
+                .iter()
+                .map(|&(day, ns)| DayTimings {
+                    day,
+                    input: None,
+                    parse_ns: 0,
+                    part1_ns: ns,
+                    part2_ns: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let e = entry("abc1234", Some("before-opt"), &[]);
+        assert!(e.matches("abc"));
+        assert!(e.matches("before-opt"));
+        assert!(!e.matches("def"));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = entry("a", None, &[(1, 100), (2, 200), (3, 300)]);
+        let current = entry("b", None, &[(1, 150), (2, 190), (4, 10)]);
+
+        let comparisons = compare(&baseline, &current);
+        assert_eq!(comparisons.len(), 2);
+        assert!((comparisons[0].change() - 50.0).abs() < 1e-9);
+        assert!(comparisons[0].regressed(10.0));
+        assert!((comparisons[1].change() + 5.0).abs() < 1e-9);
+        assert!(!comparisons[1].regressed(10.0));
+    }
+}
@@ -1,39 +1,492 @@
-use crate::solutions::{exec_all_days, exec_day};
-use humantime::format_duration;
-use std::env;
-use std::time::Duration;
+use crate::answers::{Expected, Verdict, answers_file};
+use crate::cli::{BenchArgs, Cli, Command, DaySelection, LAST_DAY, RunArgs, SolveArgs};
+use crate::client::Client;
+use crate::history::{HISTORY_FILE, HistoryEntry};
+use crate::report::RunReport;
+use crate::solutions::REGISTRY;
+use crate::solver::{
+    EXAMPLES_DIR, InputSource, Parts, Registered, RunOptions, day_inputs, example_file,
+    example_inputs, input_file,
+};
+use crate::submission::{Outcome, Submission};
+use anyhow::{Context, anyhow, bail};
+use clap::Parser;
+use rayon::prelude::*;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
+mod alloc;
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod extract;
 mod grid;
+mod history;
 #[allow(unused)]
 mod parsing;
+mod progress;
+mod report;
 mod solutions;
 mod solver;
+mod stats;
+mod submission;
+mod trace;
+mod watch;
 
-fn main() {
-    if let Some(day) = env::args().nth(1) {
-        if let Some(o) = exec_day(day.parse().unwrap_or(1)) {
-            println!("{o}");
-        } else {
-            eprintln!("Day {day} not found");
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command.unwrap_or(Command::Run(RunArgs::default())) {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Compare {
+            baseline,
+            threshold,
+        } => compare(baseline, threshold),
+        Command::Check {
+            days,
+            example,
+            record,
+        } => check(days, example, record),
+        Command::Watch {
+            day,
+            input,
+            example,
+            part,
+        } => {
+            let opts = RunOptions {
+                input: input.map(InputSource::File).unwrap_or_default(),
+                examples: example,
+                parts: part.map(Parts::only).unwrap_or_default(),
+                timeout: None,
+            };
+            watch::watch(day, &opts)
         }
-    } else {
-        let outputs = exec_all_days();
-        for (i, o) in outputs.iter().enumerate() {
-            println!("=== Day {} ===\n{o}", i + 1);
+        Command::Fetch { days, base_url } => fetch(days, &base_url),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        } => submit(day, part, answer, &base_url),
+        Command::Extract { day, page, force } => extract(day, &page, force),
+        Command::List => list(),
+        Command::New { day } => new(day),
+    }
+}
+
+/// Resolves a selection to the solvers of the available days. Without a selection, these are all
+/// the days having inputs, or examples in examples mode, while a selected day without any is
+/// reported as failed.
+fn selected_days(
+    selection: Option<&DaySelection>,
+    opts: &RunOptions,
+) -> anyhow::Result<Vec<&'static Registered>> {
+    let Some(selection) = selection else {
+        if !matches!(opts.input, InputSource::Default) {
+            return Ok(REGISTRY.iter().collect());
         }
 
-        println!("=== Global stats ===");
-        let total_parsed = outputs
+        let (found, skipped): (Vec<&Registered>, Vec<&Registered>) = REGISTRY
             .iter()
-            .map(|o| o.timings.parse_duration)
-            .sum::<Duration>();
-        let total_solved = outputs
+            .partition(|s| !opts.found_inputs(s.day).is_empty());
+        let what = if opts.examples { "examples" } else { "inputs" };
+        if found.is_empty() {
+            bail!("None of the days have {what}");
+        }
+        if !skipped.is_empty() {
+            let days = skipped.iter().map(|s| s.day).collect::<Vec<_>>();
+            eprintln!("Skipping days without {what}: {days:?}");
+        }
+        return Ok(found);
+    };
+
+    let (found, missing): (Vec<u32>, Vec<u32>) = selection
+        .days()
+        .iter()
+        .partition(|&&d| solutions::find(d).is_some());
+    match (found.is_empty(), missing.as_slice()) {
+        (_, []) => {}
+        (true, [day]) => bail!("Day {day} not found"),
+        (true, _) => bail!("None of the selected days were found"),
+        (false, missing) => eprintln!("Skipping days not found: {missing:?}"),
+    }
+
+    Ok(found.into_iter().flat_map(solutions::find).collect())
+}
+
+fn solve(args: &RunArgs) -> anyhow::Result<RunReport> {
+    if args.parallel && alloc::enabled() {
+        bail!("allocations can't be counted in parallel, phases would reset each other's peaks");
+    }
+    let opts = args.solve.options();
+    let days = selected_days(args.solve.days.as_ref(), &opts)?;
+    if !matches!(opts.input, InputSource::Default) && days.len() > 1 {
+        bail!("an input can only be given when solving a single day");
+    }
+
+    if let Some(traced) = &args.trace {
+        trace::init(args.trace_level, traced.days().to_vec());
+    }
+
+    let _progress = progress::Renderer::start();
+    let start = Instant::now();
+    let outputs = match (args.parallel, opts.timeout) {
+        // timed parts are waited for by the thread solving the day, which must not be a rayon
+        // worker since the parts themselves may need the whole pool
+        (true, Some(_)) => thread::scope(|scope| {
+            let opts = &opts;
+            let handles = days
+                .iter()
+                .map(|s| scope.spawn(move || s.solve(opts)))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("solving thread panicked"))
+                .collect()
+        }),
+        (true, None) => days.par_iter().flat_map(|s| s.solve(&opts)).collect(),
+        (false, _) => days.iter().flat_map(|s| s.solve(&opts)).collect(),
+    };
+
+    Ok(RunReport {
+        outputs,
+        wall_duration: start.elapsed(),
+        parallel: args.parallel,
+    })
+}
+
+fn run(args: &RunArgs) -> anyhow::Result<()> {
+    let report = solve(args)?;
+    let single_day = args
+        .solve
+        .days
+        .as_ref()
+        .and_then(DaySelection::single)
+        .is_some();
+    report::print(args.solve.format, &report, single_day);
+
+    match report.failed_inputs() {
+        0 => Ok(()),
+        n => bail!("{n} input(s) failed"),
+    }
+}
+
+fn bench(args: &BenchArgs) -> anyhow::Result<()> {
+    let opts = args.solve.options();
+    if let InputSource::Stdin = opts.input {
+        bail!("benchmarks can't read the input from stdin more than once");
+    }
+
+    let days = selected_days(args.solve.days.as_ref(), &opts)?;
+    if !matches!(opts.input, InputSource::Default) && days.len() > 1 {
+        bail!("an input can only be given when benchmarking a single day");
+    }
+    let runs = args.runs as usize;
+    let warmup = args.warmup as usize;
+    let mut results = vec![];
+    for solver in days {
+        results.extend(bench::bench_day(solver, &opts, runs, warmup));
+    }
+    bench::print(args.solve.format, &results, warmup);
+
+    if args.save {
+        history::append(&HistoryEntry::new(&results, args.label.clone()))?;
+    }
+
+    match results.iter().filter(|r| !r.failures.is_empty()).count() {
+        0 => Ok(()),
+        n => bail!("{n} input(s) failed"),
+    }
+}
+
+fn compare(baseline: Option<String>, threshold: f64) -> anyhow::Result<()> {
+    let entries = history::load()?;
+    let Some((current, previous)) = entries.split_last() else {
+        bail!("no benchmark saved in {HISTORY_FILE}, run `bench --save` first");
+    };
+    let baseline = match &baseline {
+        Some(name) => previous
             .iter()
-            .map(|o| o.timings.part1_duration + o.timings.part2_duration)
-            .sum::<Duration>();
+            .rev()
+            .find(|e| e.matches(name))
+            .ok_or_else(|| anyhow!("no saved benchmark named {name}"))?,
+        None => previous
+            .last()
+            .ok_or_else(|| anyhow!("only one benchmark saved, nothing to compare with"))?,
+    };
+
+    println!("Baseline: {}", baseline.describe());
+    println!("Current:  {}", current.describe());
+    let comparisons = history::compare(baseline, current);
+    history::print_comparison(&comparisons, threshold);
+
+    match comparisons
+        .iter()
+        .filter(|c| c.regressed(threshold))
+        .count()
+    {
+        0 => Ok(()),
+        n => bail!("{n} day(s) regressed by more than {threshold}%"),
+    }
+}
+
+fn check(days: Option<DaySelection>, example: bool, record: bool) -> anyhow::Result<()> {
+    let args = RunArgs {
+        solve: SolveArgs {
+            days,
+            example,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let report = solve(&args)?;
+
+    let mut failures = 0;
+    for o in report.outputs.iter() {
+        if let Some(e) = &o.input_error {
+            println!("{}: FAIL ({e})", o.title());
+            failures += 1;
+            continue;
+        }
+
+        // inputs are always read from files here
+        let Some(input_path) = &o.input_path else {
+            continue;
+        };
+        let file = answers_file(input_path);
+        let expected = Expected::load(&file)
+            .with_context(|| format!("unable to read {}", file.display()))?
+            .unwrap_or_default();
+
+        for (n, e, actual) in [
+            (1, &expected.part1, &o.part1),
+            (2, &expected.part2, &o.part2),
+        ] {
+            let verdict = Verdict::new(e.as_deref(), actual);
+            if verdict.is_failure() {
+                failures += 1;
+            }
+            println!("{} part {n}: {verdict}", o.title());
+        }
+
+        if record {
+            let recorded = Expected {
+                part1: o.part1.answer().map(str::to_string).or(expected.part1),
+                part2: o.part2.answer().map(str::to_string).or(expected.part2),
+            };
+            recorded
+                .save(&file)
+                .with_context(|| format!("unable to write {}", file.display()))?;
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => bail!("{n} check(s) failed"),
+    }
+}
+
+fn fetch(selection: Option<DaySelection>, base_url: &str) -> anyhow::Result<()> {
+    let now = SystemTime::now();
+    let days = match &selection {
+        Some(selection) => selection.days().to_vec(),
+        None => (1..=LAST_DAY)
+            .filter(|&d| client::unlock_time(d) <= now)
+            .collect(),
+    };
 
-        println!("Parsing: {}", format_duration(total_parsed));
-        println!("Solving: {}", format_duration(total_solved));
-        println!("Total:   {}", format_duration(total_parsed + total_solved));
+    let mut client = None;
+    let mut downloaded = 0;
+    for day in days {
+        let input = input_file(day);
+        // an empty file is a placeholder created by `new`
+        if fs::metadata(&input).is_ok_and(|m| m.len() > 0) {
+            println!("Day {day:02}: {input} already present");
+            continue;
+        }
+        if let Ok(wait) = client::unlock_time(day).duration_since(now) {
+            bail!(
+                "Day {day} is released in {}",
+                humantime::format_duration(Duration::from_secs(wait.as_secs()))
+            );
+        }
+
+        let client = match &mut client {
+            Some(c) => c,
+            None => client.insert(Client::from_env(base_url)?),
+        };
+        let content = client
+            .input(day)
+            .with_context(|| format!("unable to download the input of day {day}"))?;
+        fs::write(&input, content).with_context(|| format!("unable to write {input}"))?;
+        println!("Day {day:02}: downloaded {input}");
+        downloaded += 1;
     }
+
+    if downloaded > 0 {
+        println!("The solution templates of new days will be generated on next build");
+    }
+
+    Ok(())
+}
+
+fn submit(day: u32, part: u8, answer: Option<String>, base_url: &str) -> anyhow::Result<()> {
+    let input = input_file(day);
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let opts = RunOptions {
+                input: InputSource::File(input.clone().into()),
+                parts: Parts::only(part),
+                ..Default::default()
+            };
+            let o = solutions::find(day)
+                .and_then(|s| s.solve(&opts).pop())
+                .ok_or_else(|| anyhow!("Day {day} not found"))?;
+            if let Some(e) = o.failures().first() {
+                bail!("unable to solve day {day} part {part}: {e}");
+            }
+            let result = if part == 1 { o.part1 } else { o.part2 };
+            result.answer().unwrap_or_default().to_string()
+        }
+    };
+
+    let submissions = submission::load()?;
+    if let Some(reason) = submission::refusal(&submissions, day, part, &answer, submission::now()) {
+        bail!("not submitting {answer}: {reason}");
+    }
+
+    let html = Client::from_env(base_url)?
+        .submit(day, part, &answer)
+        .with_context(|| format!("unable to submit the answer of day {day} part {part}"))?;
+    let s = Submission::new(day, part, &answer, &html);
+    submission::append(&s)?;
+    println!("Day {day:02} part {part}: {answer} is {}", s.outcome);
+    if let Some(wait_until) = s.wait_until {
+        println!("Next answer accepted in {}s", wait_until - s.timestamp);
+    }
+
+    if s.outcome != Outcome::Correct {
+        bail!("answer not accepted");
+    }
+
+    // the accepted answer is now the expected one
+    let file = answers_file(Path::new(&input));
+    let mut expected = Expected::load(&file)?.unwrap_or_default();
+    if part == 1 {
+        expected.part1 = Some(answer);
+    } else {
+        expected.part2 = Some(answer);
+    }
+    expected
+        .save(&file)
+        .with_context(|| format!("unable to write {}", file.display()))
+}
+
+fn extract(day: u32, page: &Path, force: bool) -> anyhow::Result<()> {
+    let html =
+        fs::read_to_string(page).with_context(|| format!("unable to read {}", page.display()))?;
+    let files = extract::example_files(&extract::parse_page(&html));
+    if files.is_empty() {
+        bail!("no example found in {}", page.display());
+    }
+
+    let paths = files
+        .iter()
+        .map(|f| match &f.name {
+            Some(name) => PathBuf::from(format!("{EXAMPLES_DIR}/{day:02}-{name}.txt")),
+            None => PathBuf::from(example_file(day)),
+        })
+        .collect::<Vec<_>>();
+    if !force && let Some(p) = paths.iter().find(|p| p.exists()) {
+        bail!(
+            "{} already exists, use --force to overwrite it",
+            p.display()
+        );
+    }
+
+    fs::create_dir_all(EXAMPLES_DIR)?;
+    for (f, path) in files.iter().zip(paths) {
+        fs::write(&path, &f.input)
+            .with_context(|| format!("unable to write {}", path.display()))?;
+        let answers = answers_file(&path);
+        f.expected
+            .save(&answers)
+            .with_context(|| format!("unable to write {}", answers.display()))?;
+
+        let answer = |a: &Option<String>| a.clone().unwrap_or_else(|| "unknown".to_string());
+        println!(
+            "Wrote {} and {} (part 1: {}, part 2: {})",
+            path.display(),
+            answers.display(),
+            answer(&f.expected.part1),
+            answer(&f.expected.part2)
+        );
+    }
+
+    Ok(())
+}
+
+fn list() -> anyhow::Result<()> {
+    let header = ["Day", "Title", "Tags", "Status", "Input", "Examples"].map(str::to_string);
+    let rows = REGISTRY
+        .iter()
+        .map(|s| {
+            let inputs = day_inputs(s.day)
+                .iter()
+                .map(|i| i.path.display().to_string())
+                .collect::<Vec<_>>();
+            [
+                format!("{:02}", s.day),
+                s.title.to_string(),
+                s.tags.join(", "),
+                match s.implemented {
+                    true => "done".to_string(),
+                    false => format!("todo in src/solutions/{}.rs", s.module),
+                },
+                match inputs.is_empty() {
+                    true => format!("{} (missing)", input_file(s.day)),
+                    false => inputs.join(", "),
+                },
+                example_inputs(s.day).len().to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = [0; 6];
+    for row in rows.iter().chain([&header]) {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+    for row in [&header].into_iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{cell:<w$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    Ok(())
+}
+
+fn new(day: u32) -> anyhow::Result<()> {
+    let input = input_file(day);
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&input)
+        .with_context(|| format!("unable to create {input}"))?;
+
+    println!(
+        "Created {input}, the solution template src/solutions/day{day:02}.rs will be generated on next build"
+    );
+
+    Ok(())
 }
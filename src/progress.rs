@@ -0,0 +1,184 @@
+use crate::trace;
+use std::cell::Cell;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: usize = 30;
+
+struct Bar {
+    day: Option<u32>,
+    part: Option<u64>,
+    total: u64,
+    done: AtomicU64,
+}
+
+/// Bars of the solvers currently reporting progress.
+static BARS: Mutex<Vec<Arc<Bar>>> = Mutex::new(Vec::new());
+
+/// Parts given up on after a timeout, whose bars are not drawn anymore.
+static ABANDONED: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+static NEXT_PART: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Part run on the current thread, owning the bars started from it
+    static CURRENT_PART: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// A new id for a part run on its own thread, to pass to [`enter_part`] on that thread.
+pub fn new_part() -> u64 {
+    NEXT_PART.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Attributes the bars started from the current thread to `part`.
+pub fn enter_part(part: u64) {
+    CURRENT_PART.set(Some(part));
+}
+
+/// Removes the bars of a part that was given up on, including the ones it starts later.
+pub fn abandon(part: u64) {
+    ABANDONED.lock().unwrap().push(part);
+    BARS.lock().unwrap().retain(|b| b.part != Some(part));
+}
+
+/// Handle through which a solver reports its progress, shareable with parallel iterators.
+/// The bar disappears when the handle is dropped.
+pub struct Progress(Arc<Bar>);
+
+/// Starts reporting the progress of `total` units of work for the current day.
+pub fn start(total: u64) -> Progress {
+    let part = CURRENT_PART.get();
+    let bar = Arc::new(Bar {
+        day: trace::current_day(),
+        part,
+        total,
+        done: AtomicU64::new(0),
+    });
+    let abandoned = ABANDONED.lock().unwrap();
+    if !part.is_some_and(|p| abandoned.contains(&p)) {
+        BARS.lock().unwrap().push(bar.clone());
+    }
+    Progress(bar)
+}
+
+impl Progress {
+    /// Records `n` more units of work as done.
+    pub fn inc(&self, n: u64) {
+        self.0.done.fetch_add(n, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        BARS.lock().unwrap().retain(|b| !Arc::ptr_eq(b, &self.0));
+    }
+}
+
+fn render_bar(day: Option<u32>, done: u64, total: u64) -> String {
+    let ratio = if total == 0 {
+        1.0
+    } else {
+        (done as f64 / total as f64).min(1.0)
+    };
+    let filled = (ratio * BAR_WIDTH as f64) as usize;
+    let label = day.map(|d| format!("Day {d:02} ")).unwrap_or_default();
+
+    format!(
+        "{label}[{}{}] {:>3.0}% ({done}/{total})",
+        "#".repeat(filled),
+        ".".repeat(BAR_WIDTH - filled),
+        ratio * 100.0
+    )
+}
+
+fn render() -> String {
+    BARS.lock()
+        .unwrap()
+        .iter()
+        .map(|b| render_bar(b.day, b.done.load(Ordering::Relaxed), b.total))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Draws the progress bars on stderr until dropped.
+pub struct Renderer {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Renderer {
+    /// Starts drawing, only when stderr is a terminal.
+    pub fn start() -> Option<Self> {
+        if !io::stderr().is_terminal() {
+            return None;
+        }
+
+        let (stop, rx) = mpsc::channel::<()>();
+        let thread = thread::spawn(move || {
+            let mut drawn = false;
+            // the sender is dropped to stop
+            while let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(REFRESH_INTERVAL) {
+                let line = render();
+                if drawn || !line.is_empty() {
+                    // go back to the start of the line and clear it
+                    eprint!("\r\x1b[K{line}");
+                    let _ = io::stderr().flush();
+                    drawn = !line.is_empty();
+                }
+            }
+            if drawn {
+                eprint!("\r\x1b[K");
+            }
+        });
+
+        Some(Self {
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bar() {
+        assert_eq!(
+            render_bar(Some(2), 15, 30),
+            "Day 02 [###############...............]  50% (15/30)"
+        );
+        assert_eq!(
+            render_bar(None, 0, 0),
+            "[##############################] 100% (0/0)"
+        );
+    }
+
+    #[test]
+    fn test_abandon() {
+        let drawn = |p: &Progress| BARS.lock().unwrap().iter().any(|b| Arc::ptr_eq(b, &p.0));
+
+        let part = new_part();
+        enter_part(part);
+        let before = start(10);
+        assert!(drawn(&before));
+
+        abandon(part);
+        let after = start(10);
+        assert!(!drawn(&before));
+        assert!(!drawn(&after));
+    }
+}
@@ -0,0 +1,329 @@
+use crate::alloc::{AllocStats, PhaseAllocs, format_bytes};
+use crate::solver::{ProblemOutput, Timings};
+use clap::ValueEnum;
+use humantime::format_duration;
+use serde_json::{Value, json};
+use std::cmp::Reverse;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub struct RunReport {
+    pub outputs: Vec<ProblemOutput>,
+    /// Wall-clock time of the whole run, lower than the summed timings when run in parallel
+    pub wall_duration: Duration,
+    pub parallel: bool,
+}
+
+impl RunReport {
+    pub fn failed_inputs(&self) -> usize {
+        self.outputs.iter().filter(|o| o.failed()).count()
+    }
+}
+
+pub fn print(format: Format, report: &RunReport, single_day: bool) {
+    match (format, report.outputs.as_slice()) {
+        (Format::Text, [o]) if single_day => println!("{o}"),
+        (Format::Text, _) => print_text(report),
+        // always the same shape, whatever the number of inputs, for scripts
+        (Format::Json, _) => println!("{:#}", all_days_json(report)),
+        (Format::Csv, outputs) => print_csv(outputs),
+    }
+}
+
+/// Number of slowest days highlighted in the summary table.
+const SLOWEST_HIGHLIGHTED: usize = 3;
+
+const BOLD: &str = "1";
+const RED: &str = "31";
+const YELLOW: &str = "33";
+
+/// Wraps `s` in an ANSI SGR sequence when `color` is set.
+fn paint(s: String, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{code}m{s}\x1b[0m")
+    } else {
+        s
+    }
+}
+
+fn print_text(report: &RunReport) {
+    let color = io::stdout().is_terminal();
+    let totals = totals(&report.outputs);
+    let share = |d: Duration| {
+        let percent = 100.0 * d.as_secs_f64() / totals.total_duration().as_secs_f64().max(1e-9);
+        format!("{percent:.1}%")
+    };
+
+    let mut by_time = report.outputs.iter().collect::<Vec<_>>();
+    by_time.sort_by_key(|o| Reverse(o.timings.total_duration()));
+    let slowest = by_time
+        .iter()
+        .take(SLOWEST_HIGHLIGHTED.min(report.outputs.len().saturating_sub(1)))
+        .map(|o| (o.day, &o.input))
+        .collect::<Vec<_>>();
+    let is_slowest = |o: &ProblemOutput| slowest.contains(&(o.day, &o.input));
+
+    // allocation columns, only counted with the `count-allocs` feature
+    let with_allocs = report.outputs.iter().any(|o| o.allocs.is_some());
+    let alloc_cells = |a: Option<AllocStats>| match (with_allocs, a) {
+        (false, _) => vec![],
+        (true, None) => vec![String::new(); 3],
+        (true, Some(a)) => vec![
+            a.allocations.to_string(),
+            format_bytes(a.bytes),
+            format_bytes(a.peak_bytes),
+        ],
+    };
+
+    let mut header = [
+        "Day", "Answer 1", "Answer 2", "Parse", "Part 1", "Part 2", "Total", "Share",
+    ]
+    .map(str::to_string)
+    .to_vec();
+    if with_allocs {
+        header.extend(["Allocs", "Allocated", "Peak"].map(str::to_string));
+    }
+    header.push(String::new());
+    let rows = report
+        .outputs
+        .iter()
+        .map(|o| {
+            let mut row = vec![
+                match &o.input {
+                    Some(name) => format!("{:02} {name}", o.day),
+                    None => format!("{:02}", o.day),
+                },
+                o.part1.to_string(),
+                o.part2.to_string(),
+                format_short(o.timings.parse_duration),
+                format_short(o.timings.part1_duration),
+                match o.combined {
+                    // counted in part 1
+                    Some(_) => "-".to_string(),
+                    None => format_short(o.timings.part2_duration),
+                },
+                format_short(o.timings.total_duration()),
+                share(o.timings.total_duration()),
+            ];
+            row.extend(alloc_cells(o.allocs.as_ref().map(PhaseAllocs::total)));
+            row.push(if is_slowest(o) { "*" } else { "" }.to_string());
+            row
+        })
+        .collect::<Vec<_>>();
+    let mut footer = vec![
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        format_short(totals.parse_duration),
+        format_short(totals.part1_duration),
+        format_short(totals.part2_duration),
+        format_short(totals.total_duration()),
+        share(totals.total_duration()),
+    ];
+    let all_allocs = report
+        .outputs
+        .iter()
+        .filter_map(|o| o.allocs.as_ref().map(PhaseAllocs::total))
+        .collect::<Vec<_>>();
+    footer.extend(alloc_cells(Some(AllocStats::combined(&all_allocs))));
+    footer.push(String::new());
+
+    let mut widths = vec![0; header.len()];
+    for row in rows.iter().chain([&header, &footer]) {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+    let last = header.len() - 1;
+    let line = |row: &Vec<String>| {
+        row.iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &w))| {
+                if i == 0 || i == last {
+                    format!("{cell:<w$}")
+                } else {
+                    format!("{cell:>w$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+    let lines = rows.iter().map(line).collect::<Vec<_>>();
+    let separator = "-".repeat(lines.iter().map(String::len).max().unwrap_or_default());
+
+    println!("{}", paint(line(&header), BOLD, color));
+    println!("{separator}");
+    for (o, row) in report.outputs.iter().zip(lines) {
+        if o.failed() {
+            println!("{}", paint(row, RED, color));
+        } else if is_slowest(o) {
+            println!("{}", paint(row, YELLOW, color));
+        } else {
+            println!("{row}");
+        }
+    }
+    println!("{separator}");
+    println!("{}", paint(line(&footer), BOLD, color));
+    if !slowest.is_empty() {
+        println!("* slowest inputs");
+    }
+
+    print_global_stats(report);
+}
+
+fn print_global_stats(report: &RunReport) {
+    if report.parallel {
+        let totals = totals(&report.outputs);
+        let speedup =
+            totals.total_duration().as_secs_f64() / report.wall_duration.as_secs_f64().max(1e-9);
+        println!(
+            "Wall: {} ({speedup:.2}x speedup)",
+            format_duration(report.wall_duration)
+        );
+    }
+
+    let failed = report
+        .outputs
+        .iter()
+        .filter(|o| o.failed())
+        .collect::<Vec<_>>();
+    if !failed.is_empty() {
+        println!("=== Failures ===");
+        for o in failed {
+            for f in o.failures() {
+                println!("{}: {f}", o.title());
+            }
+        }
+    }
+}
+
+fn totals(outputs: &[ProblemOutput]) -> Timings {
+    Timings {
+        parse_duration: outputs.iter().map(|o| o.timings.parse_duration).sum(),
+        part1_duration: outputs.iter().map(|o| o.timings.part1_duration).sum(),
+        part2_duration: outputs.iter().map(|o| o.timings.part2_duration).sum(),
+    }
+}
+
+pub fn nanos(d: Duration) -> u64 {
+    d.as_nanos() as u64
+}
+
+/// Formats a duration with a single unit and 3 significant decimals, for aligned columns.
+pub fn format_short(d: Duration) -> String {
+    let ns = d.as_nanos();
+    match ns {
+        0..1_000 => format!("{ns}ns"),
+        1_000..1_000_000 => format!("{:.3}us", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.3}ms", ns as f64 / 1e6),
+        _ => format!("{:.3}s", d.as_secs_f64()),
+    }
+}
+
+fn allocs_json(a: &AllocStats) -> Value {
+    json!({
+        "allocations": a.allocations,
+        "bytes": a.bytes,
+        "peak_bytes": a.peak_bytes,
+    })
+}
+
+fn day_json(o: &ProblemOutput) -> Value {
+    let mut day = json!({
+        "day": o.day,
+        "input": o.input,
+        "part1": o.part1.answer(),
+        "part2": o.part2.answer(),
+        "timings": {
+            "parse_ns": nanos(o.timings.parse_duration),
+            "part1_ns": nanos(o.timings.part1_duration),
+            "part2_ns": nanos(o.timings.part2_duration),
+        },
+        "errors": o.failures(),
+    });
+    if let Some(a) = &o.allocs {
+        day["allocs"] = json!({
+            "parse": allocs_json(&a.parse),
+            "part1": allocs_json(&a.part1),
+            "part2": allocs_json(&a.part2),
+        });
+    }
+    if let Some(laps) = &o.combined {
+        day["combined"] = laps
+            .iter()
+            .map(|(name, d)| json!({ "phase": name, "ns": nanos(*d) }))
+            .collect();
+    }
+    day
+}
+
+fn all_days_json(report: &RunReport) -> Value {
+    let totals = totals(&report.outputs);
+
+    json!({
+        "days": report.outputs.iter().map(day_json).collect::<Vec<_>>(),
+        "parallel": report.parallel,
+        "totals": {
+            "parse_ns": nanos(totals.parse_duration),
+            "solve_ns": nanos(totals.solve_duration()),
+            "total_ns": nanos(totals.total_duration()),
+            "wall_ns": nanos(report.wall_duration),
+        },
+    })
+}
+
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn print_csv(outputs: &[ProblemOutput]) {
+    println!("day,input,part1,part2,parse_ns,part1_ns,part2_ns,errors");
+    for o in outputs {
+        println!(
+            "{},{},{},{},{},{},{},{}",
+            o.day,
+            csv_field(o.input.as_deref().unwrap_or_default()),
+            csv_field(o.part1.answer().unwrap_or_default()),
+            csv_field(o.part2.answer().unwrap_or_default()),
+            nanos(o.timings.parse_duration),
+            nanos(o.timings.part1_duration),
+            nanos(o.timings.part2_duration),
+            csv_field(&o.failures().join("; "))
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_short() {
+        assert_eq!(format_short(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_short(Duration::from_nanos(12_345)), "12.345us");
+        assert_eq!(format_short(Duration::from_micros(1_500)), "1.500ms");
+        assert_eq!(format_short(Duration::from_millis(2_250)), "2.250s");
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+        assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+    }
+}
@@ -9,6 +9,9 @@ use std::str::FromStr;
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Secret Entrance";
+    const TAGS: &'static [&'static str] = &["simulation", "modular"];
+
     type Input = Vec<Instruction>;
     type Output1 = usize;
     type Output2 = usize;
@@ -1,14 +1,20 @@
 use crate::parsing::BufReadExt;
+use crate::progress;
 use crate::solver::Solver;
+use crate::trace::{self, trace};
 use anyhow::anyhow;
 use rayon::prelude::*;
 use sscanf::sscanf;
 use std::io::BufRead;
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Gift Shop";
+    const TAGS: &'static [&'static str] = &["ranges", "brute-force", "parallel"];
+
     type Input = Vec<Range>;
     type Output1 = u64;
     type Output2 = u64;
@@ -18,25 +24,11 @@ impl Solver for Problem {
     }
 
     fn solve_first(&self, input: &Self::Input) -> Self::Output1 {
-        input
-            .into_par_iter()
-            .flat_map(|r| {
-                (r.start..=r.end)
-                    .into_par_iter()
-                    .filter(|&n| is_id_invalid(n))
-            })
-            .sum::<u64>()
+        sum_matching(input, is_id_invalid)
     }
 
     fn solve_second(&self, input: &Self::Input) -> Self::Output2 {
-        input
-            .into_par_iter()
-            .flat_map(|r| {
-                (r.start..=r.end)
-                    .into_par_iter()
-                    .filter(|&n| is_id_really_invalid2(n))
-            })
-            .sum::<u64>()
+        sum_matching(input, is_id_really_invalid2)
     }
 }
 
@@ -45,6 +37,21 @@ pub struct Range {
     end: u64,
 }
 
+/// Number of ids per unit of work, small enough for a smooth progress report.
+const CHUNK_SIZE: u64 = 100_000;
+
+impl Range {
+    fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    fn chunks(&self) -> impl Iterator<Item = RangeInclusive<u64>> {
+        (self.start..=self.end)
+            .step_by(CHUNK_SIZE as usize)
+            .map(|s| s..=self.end.min(s + CHUNK_SIZE - 1))
+    }
+}
+
 impl FromStr for Range {
     type Err = anyhow::Error;
 
@@ -54,6 +61,24 @@ impl FromStr for Range {
     }
 }
 
+/// Sums the ids of all ranges matching `f`, reporting progress.
+fn sum_matching(input: &[Range], f: fn(u64) -> bool) -> u64 {
+    let progress = progress::start(input.iter().map(Range::len).sum());
+    // the closures run on rayon threads, which don't know the day being solved
+    let tracer = trace::tracer();
+    input
+        .par_iter()
+        .flat_map_iter(Range::chunks)
+        .map(|ids| {
+            let n = ids.end() - ids.start() + 1;
+            let sum = ids.clone().filter(|&id| f(id)).sum::<u64>();
+            trace!(tracer; "ids {ids:?}: {sum}");
+            progress.inc(n);
+            sum
+        })
+        .sum()
+}
+
 fn is_id_invalid(id: u64) -> bool {
     // convert to string
     // if len is odd, id is valid
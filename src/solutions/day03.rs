@@ -6,6 +6,9 @@ use std::str::FromStr;
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Lobby";
+    const TAGS: &'static [&'static str] = &["greedy"];
+
     type Input = Vec<Bank>;
     type Output1 = u64;
     type Output2 = u64;
@@ -1,10 +1,14 @@
 use crate::grid::Grid;
-use crate::solver::Solver;
+use crate::solver::{Phases, Solver};
 use std::io::BufRead;
 
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Printing Department";
+    const TAGS: &'static [&'static str] = &["grid", "simulation"];
+    const COMBINED: bool = true;
+
     type Input = Grid<Entry>;
     type Output1 = usize;
     type Output2 = usize;
@@ -18,23 +22,23 @@ impl Solver for Problem {
     }
 
     fn solve_second(&self, input: &Self::Input) -> Self::Output2 {
+        remove_all(&mut input.clone())
+    }
+
+    fn solve_both(
+        &self,
+        input: &Self::Input,
+        phases: &mut Phases,
+    ) -> (Self::Output1, Self::Output2) {
+        // the rolls removable at first are the answer of part 1
         let mut g = input.clone();
-        let mut removed = 0;
-        loop {
-            let to_remove = removable_coords(&g);
-            if to_remove.is_empty() {
-                break;
-            }
+        let first = remove_wave(&mut g);
+        phases.lap("first wave");
 
-            removed += to_remove.len();
-            for c in to_remove {
-                if let Some(e) = g.get_mut(c) {
-                    *e = Entry::Empty;
-                }
-            }
-        }
+        let removed = first + remove_all(&mut g);
+        phases.lap("other waves");
 
-        removed
+        (first, removed)
     }
 }
 
@@ -69,3 +73,27 @@ fn removable_coords(g: &Grid<Entry>) -> Vec<(usize, usize)> {
         .map(|(c, _)| c)
         .collect()
 }
+
+/// Removes all the currently removable rolls, returning how many there were.
+fn remove_wave(g: &mut Grid<Entry>) -> usize {
+    let to_remove = removable_coords(g);
+    for &c in &to_remove {
+        if let Some(e) = g.get_mut(c) {
+            *e = Entry::Empty;
+        }
+    }
+    to_remove.len()
+}
+
+/// Removes waves of rolls until none is removable, returning how many were removed.
+fn remove_all(g: &mut Grid<Entry>) -> usize {
+    let mut removed = 0;
+    loop {
+        let n = remove_wave(g);
+        if n == 0 {
+            break;
+        }
+        removed += n;
+    }
+    removed
+}
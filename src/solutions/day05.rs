@@ -9,6 +9,9 @@ use std::str::FromStr;
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Cafeteria";
+    const TAGS: &'static [&'static str] = &["ranges", "parsing-heavy"];
+
     type Input = Ingredients;
     type Output1 = u64;
     type Output2 = u64;
@@ -1,5 +1,6 @@
 use crate::grid::Grid;
 use crate::solver::Solver;
+use crate::trace::{debug, trace};
 use anyhow::anyhow;
 use std::fmt::Display;
 use std::io::BufRead;
@@ -8,9 +9,12 @@ use std::str::FromStr;
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Trash Compactor";
+    const TAGS: &'static [&'static str] = &["grid", "parsing-heavy"];
+
     type Input = Vec<u8>;
-    type Output1 = u64;
-    type Output2 = u64;
+    type Output1 = anyhow::Result<u64>;
+    type Output2 = anyhow::Result<u64>;
 
     fn parse_input<R: BufRead>(&self, mut r: R) -> anyhow::Result<Self::Input> {
         let mut v = vec![];
@@ -19,18 +23,20 @@ impl Solver for Problem {
     }
 
     fn solve_first(&self, input: &Self::Input) -> Self::Output1 {
-        let g = Grid::from_split_whitespace_reader(input.as_slice()).expect("valid grid");
-        (0..g.w).map(|c| eval_col(&g, c)).sum()
+        let g = Grid::from_split_whitespace_reader(input.as_slice())?;
+        debug!("{}x{} grid:\n{g}", g.w, g.h);
+        Ok((0..g.w).map(|c| eval_col(&g, c)).sum())
     }
 
     fn solve_second(&self, input: &Self::Input) -> Self::Output2 {
-        let g: Grid<u8> = Grid::from_reader(input.as_slice()).expect("valid grid");
+        let g: Grid<u8> = Grid::from_reader(input.as_slice())?;
 
         // divide into groups of columns separated by spaces, sum all groups
         let rows = (0..g.w).collect::<Vec<_>>();
-        rows.split(|&c| g.iter_col(c).all(|&b| b == b' '))
+        Ok(rows
+            .split(|&c| g.iter_col(c).all(|&b| b == b' '))
             .map(|cols| eval_col2(&g, cols))
-            .sum()
+            .sum())
     }
 }
 
@@ -103,6 +109,7 @@ fn eval_col2(g: &Grid<u8>, cols: &[usize]) -> u64 {
         numbers.push(accum);
     }
 
+    trace!("columns {cols:?}: {numbers:?}");
     match op {
         Some(b'+') => numbers.iter().sum(),
         Some(b'*') => numbers.iter().product(),
@@ -123,7 +130,6 @@ mod tests {
 *   +   *   +"
             .as_bytes();
         let g: Grid<Entry> = Grid::from_split_whitespace_reader(s).unwrap();
-
-        println!("{g}");
+        assert_eq!((g.w, g.h), (4, 4));
     }
 }
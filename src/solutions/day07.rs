@@ -1,75 +1,90 @@
 use crate::grid::{Coord, Grid};
-use crate::solver::Solver;
-use std::collections::{BTreeMap, BTreeSet};
+use crate::solver::{Phases, Solver};
+use anyhow::anyhow;
+use std::collections::BTreeMap;
 use std::io::BufRead;
 
 pub struct Problem;
 
 impl Solver for Problem {
+    const TITLE: &'static str = "Laboratories";
+    const TAGS: &'static [&'static str] = &["grid", "simulation", "dynamic-programming"];
+    const COMBINED: bool = true;
+
     type Input = Grid<Entry>;
-    type Output1 = usize;
-    type Output2 = usize;
+    type Output1 = anyhow::Result<usize>;
+    type Output2 = anyhow::Result<usize>;
 
     fn parse_input<R: BufRead>(&self, r: R) -> anyhow::Result<Self::Input> {
         Grid::from_reader(r)
     }
 
     fn solve_first(&self, input: &Self::Input) -> Self::Output1 {
-        let (start, _) = input
-            .iter_with_coords()
-            .find(|&(_, e)| e.eq(&Entry::Start))
-            .expect("No start");
-        let mut tachyons = BTreeSet::from([start]);
-        let mut splits = 0;
-
-        for _ in start.y()..input.h {
-            let mut next_tachyons = BTreeSet::new();
-            for &(x, y) in tachyons.iter() {
-                if let Some(Entry::Splitter) = input.get((x, y + 1)) {
-                    next_tachyons.insert((x - 1, y + 1));
-                    next_tachyons.insert((x + 1, y + 1));
-                    splits += 1;
-                } else {
-                    next_tachyons.insert((x, y + 1));
-                }
-            }
-            tachyons = next_tachyons;
-        }
-
-        splits
+        let start = find_start(input)?;
+        Ok(simulate(input, start).0)
     }
 
     fn solve_second(&self, input: &Self::Input) -> Self::Output2 {
-        let (start, _) = input
-            .iter_with_coords()
-            .find(|&(_, e)| e.eq(&Entry::Start))
-            .expect("No start");
-        let mut tachyons = BTreeMap::from([(start, 1)]);
-
-        for _ in start.y()..input.h {
-            let mut next_tachyons = BTreeMap::new();
-            for (&(x, y), &n) in tachyons.iter() {
-                if let Some(Entry::Splitter) = input.get((x, y + 1)) {
-                    next_tachyons
-                        .entry((x - 1, y + 1))
-                        .and_modify(|e| *e += n)
-                        .or_insert(n);
-                    next_tachyons
-                        .entry((x + 1, y + 1))
-                        .and_modify(|e| *e += n)
-                        .or_insert(n);
-                } else {
-                    next_tachyons
-                        .entry((x, y + 1))
-                        .and_modify(|e| *e += n)
-                        .or_insert(n);
-                }
+        let start = find_start(input)?;
+        Ok(simulate(input, start).1)
+    }
+
+    fn solve_both(
+        &self,
+        input: &Self::Input,
+        phases: &mut Phases,
+    ) -> (Self::Output1, Self::Output2) {
+        let start = match find_start(input) {
+            Ok(start) => start,
+            Err(e) => return (Err(anyhow!("{e:#}")), Err(e)),
+        };
+        phases.lap("find start");
+
+        let (splits, timelines) = simulate(input, start);
+        phases.lap("simulate");
+
+        (Ok(splits), Ok(timelines))
+    }
+}
+
+fn find_start(input: &Grid<Entry>) -> anyhow::Result<(usize, usize)> {
+    input
+        .iter_with_coords()
+        .find(|&(_, e)| e.eq(&Entry::Start))
+        .map(|(c, _)| c)
+        .ok_or_else(|| anyhow!("no start"))
+}
+
+/// Follows the beams down from the start, returning the number of splits and the number of
+/// timelines, i.e. of distinct paths, at the bottom.
+fn simulate(input: &Grid<Entry>, start: (usize, usize)) -> (usize, usize) {
+    let mut tachyons = BTreeMap::from([(start, 1)]);
+    let mut splits = 0;
+
+    for _ in start.y()..input.h {
+        let mut next_tachyons = BTreeMap::new();
+        for (&(x, y), &n) in tachyons.iter() {
+            if let Some(Entry::Splitter) = input.get((x, y + 1)) {
+                next_tachyons
+                    .entry((x - 1, y + 1))
+                    .and_modify(|e| *e += n)
+                    .or_insert(n);
+                next_tachyons
+                    .entry((x + 1, y + 1))
+                    .and_modify(|e| *e += n)
+                    .or_insert(n);
+                splits += 1;
+            } else {
+                next_tachyons
+                    .entry((x, y + 1))
+                    .and_modify(|e| *e += n)
+                    .or_insert(n);
             }
-            tachyons = next_tachyons;
         }
-
-        tachyons.values().sum()
+        tachyons = next_tachyons;
     }
+
+    (splits, tachyons.values().sum())
 }
 
 #[derive(Eq, PartialEq)]
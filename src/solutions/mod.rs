@@ -1,4 +1,4 @@
-use crate::solver::{ProblemOutput, Solver};
+use crate::solver::{Registered, Solver};
 mod day01;
 mod day02;
 mod day03;
@@ -6,22 +6,66 @@ mod day04;
 mod day05;
 mod day06;
 mod day07;
-pub fn exec_day(day: u32) -> Option<ProblemOutput> {
-    match day {
-        1u32 => Some(day01::Problem.solve(day)),
-        2u32 => Some(day02::Problem.solve(day)),
-        3u32 => Some(day03::Problem.solve(day)),
-        4u32 => Some(day04::Problem.solve(day)),
-        5u32 => Some(day05::Problem.solve(day)),
-        6u32 => Some(day06::Problem.solve(day)),
-        7u32 => Some(day07::Problem.solve(day)),
-        _ => None,
-    }
-}
-pub fn exec_all_days() -> Vec<ProblemOutput> {
-    vec![
-        day01::Problem.solve(1u32), day02::Problem.solve(2u32), day03::Problem
-        .solve(3u32), day04::Problem.solve(4u32), day05::Problem.solve(5u32),
-        day06::Problem.solve(6u32), day07::Problem.solve(7u32)
-    ]
+/// The solvers of all days, in order.
+pub static REGISTRY: &[Registered] = &[
+    Registered {
+        day: 1u32,
+        module: "day01",
+        title: <day01::Problem as Solver>::TITLE,
+        tags: <day01::Problem as Solver>::TAGS,
+        implemented: <day01::Problem as Solver>::IMPLEMENTED,
+        solver: &day01::Problem,
+    },
+    Registered {
+        day: 2u32,
+        module: "day02",
+        title: <day02::Problem as Solver>::TITLE,
+        tags: <day02::Problem as Solver>::TAGS,
+        implemented: <day02::Problem as Solver>::IMPLEMENTED,
+        solver: &day02::Problem,
+    },
+    Registered {
+        day: 3u32,
+        module: "day03",
+        title: <day03::Problem as Solver>::TITLE,
+        tags: <day03::Problem as Solver>::TAGS,
+        implemented: <day03::Problem as Solver>::IMPLEMENTED,
+        solver: &day03::Problem,
+    },
+    Registered {
+        day: 4u32,
+        module: "day04",
+        title: <day04::Problem as Solver>::TITLE,
+        tags: <day04::Problem as Solver>::TAGS,
+        implemented: <day04::Problem as Solver>::IMPLEMENTED,
+        solver: &day04::Problem,
+    },
+    Registered {
+        day: 5u32,
+        module: "day05",
+        title: <day05::Problem as Solver>::TITLE,
+        tags: <day05::Problem as Solver>::TAGS,
+        implemented: <day05::Problem as Solver>::IMPLEMENTED,
+        solver: &day05::Problem,
+    },
+    Registered {
+        day: 6u32,
+        module: "day06",
+        title: <day06::Problem as Solver>::TITLE,
+        tags: <day06::Problem as Solver>::TAGS,
+        implemented: <day06::Problem as Solver>::IMPLEMENTED,
+        solver: &day06::Problem,
+    },
+    Registered {
+        day: 7u32,
+        module: "day07",
+        title: <day07::Problem as Solver>::TITLE,
+        tags: <day07::Problem as Solver>::TAGS,
+        implemented: <day07::Problem as Solver>::IMPLEMENTED,
+        solver: &day07::Problem,
+    },
+];
+/// The solver of a day, if it is registered.
+pub fn find(day: u32) -> Option<&'static Registered> {
+    REGISTRY.iter().find(|s| s.day == day)
 }
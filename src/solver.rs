@@ -1,88 +1,763 @@
+use crate::alloc::{self, AllocStats, Measure, PhaseAllocs};
+use crate::{progress, trace};
+use anyhow::Context;
 use humantime::format_duration;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, stdin};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::{Duration, Instant};
 
-fn input_file(day: u32) -> String {
+pub fn input_file(day: u32) -> String {
     format!("input/{:02}.txt", day)
 }
 
+pub const EXAMPLES_DIR: &str = "input/examples";
+
+pub fn example_file(day: u32) -> String {
+    format!("{EXAMPLES_DIR}/{:02}.txt", day)
+}
+
+/// An input file of a day, `NN.txt` or the named `NN-name.txt`.
+#[derive(Clone, Debug)]
+pub struct DayInput {
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+/// Splits an input file name into its day and optional name, like `input_day` of `build.rs`.
+fn parse_input_name(file_name: &str) -> Option<(u32, Option<&str>)> {
+    let stem = file_name.strip_suffix(".txt")?;
+    let (day, name) = match stem.split_once('-') {
+        Some((_, "")) => return None,
+        Some((day, name)) => (day, Some(name)),
+        None => (stem, None),
+    };
+    if day.len() != 2 {
+        return None;
+    }
+
+    Some((day.parse().ok()?, name))
+}
+
+/// The input files of a day in `dir`, the unnamed one first.
+fn inputs_in(dir: &str, day: u32) -> Vec<DayInput> {
+    let mut inputs = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter_map(|path| {
+            let (d, name) = parse_input_name(path.file_name()?.to_str()?)?;
+            let name = name.map(str::to_string);
+            (d == day).then_some(DayInput { name, path })
+        })
+        .collect::<Vec<_>>();
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    inputs
+}
+
+/// All the input files of a day, `input/NN.txt` and `input/NN-name.txt`.
+pub fn day_inputs(day: u32) -> Vec<DayInput> {
+    inputs_in("input", day)
+}
+
+/// All the example inputs of a day, `input/examples/NN.txt` and `input/examples/NN-name.txt`,
+/// named `example` and `example-name`.
+pub fn example_inputs(day: u32) -> Vec<DayInput> {
+    inputs_in(EXAMPLES_DIR, day)
+        .into_iter()
+        .map(|i| DayInput {
+            name: Some(match i.name {
+                Some(name) => format!("example-{name}"),
+                None => "example".to_string(),
+            }),
+            path: i.path,
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Default)]
+pub enum InputSource {
+    /// The day's input file, see [`input_file`]
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Parts {
+    pub first: bool,
+    pub second: bool,
+}
+
+impl Parts {
+    pub fn only(part: u8) -> Self {
+        Self {
+            first: part == 1,
+            second: part == 2,
+        }
+    }
+}
+
+impl Default for Parts {
+    fn default() -> Self {
+        Self {
+            first: true,
+            second: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub input: InputSource,
+    /// Solve the example inputs instead of the real ones
+    pub examples: bool,
+    pub parts: Parts,
+    /// Time budget of each part, shared by both parts when they are solved together
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// The input files to solve a day on, when no input is given. A missing input file is still
+    /// returned, for its absence to be reported.
+    pub fn day_inputs(&self, day: u32) -> Vec<DayInput> {
+        let inputs = self.found_inputs(day);
+        if !inputs.is_empty() {
+            return inputs;
+        }
+
+        let default = if self.examples {
+            example_file(day)
+        } else {
+            input_file(day)
+        };
+        vec![DayInput {
+            name: self.examples.then(|| "example".to_string()),
+            path: PathBuf::from(default),
+        }]
+    }
+
+    /// The existing input files of a day, its examples in examples mode.
+    pub fn found_inputs(&self, day: u32) -> Vec<DayInput> {
+        if self.examples {
+            example_inputs(day)
+        } else {
+            day_inputs(day)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
 pub struct Timings {
     pub parse_duration: Duration,
     pub part1_duration: Duration,
     pub part2_duration: Duration,
 }
 
+impl Timings {
+    pub fn solve_duration(&self) -> Duration {
+        self.part1_duration + self.part2_duration
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.parse_duration + self.solve_duration()
+    }
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Hook replaced while days are being solved, and the number of days being solved.
+static QUIET_PANICS: Mutex<(usize, Option<PanicHook>)> = Mutex::new((0, None));
+
+/// Silences panic messages while alive, the panics of parts being reported as failures instead.
+struct QuietPanics;
+
+impl QuietPanics {
+    fn new() -> Self {
+        let mut quiet = QUIET_PANICS.lock().unwrap();
+        if quiet.0 == 0 {
+            quiet.1 = Some(panic::take_hook());
+            panic::set_hook(Box::new(|_| {}));
+        }
+        quiet.0 += 1;
+        QuietPanics
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        let mut quiet = QUIET_PANICS.lock().unwrap();
+        quiet.0 -= 1;
+        // the hook can't be changed while panicking
+        if quiet.0 == 0
+            && !thread::panicking()
+            && let Some(hook) = quiet.1.take()
+        {
+            panic::set_hook(hook);
+        }
+    }
+}
+
+/// Runs `f`, turning a panic into an error holding the panic message.
+fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(s) = payload.downcast_ref::<&str>() {
+            format!("panicked: {s}")
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            format!("panicked: {s}")
+        } else {
+            "panicked".to_string()
+        }
+    })
+}
+
+/// Value returned by a part: a displayable answer, or an `anyhow::Result` of one for parts which
+/// can fail.
+pub trait Answer {
+    fn into_answer(self) -> anyhow::Result<String>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn into_answer(self) -> anyhow::Result<String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize);
+impl_answer!(i8, i16, i32, i64, i128, isize);
+impl_answer!(char, String, &'static str);
+
+impl<T: Answer> Answer for anyhow::Result<T> {
+    fn into_answer(self) -> anyhow::Result<String> {
+        self?.into_answer()
+    }
+}
+
+pub enum PartResult {
+    Answer(String),
+    Skipped,
+    Failed(String),
+    /// The part was abandoned after exceeding the time limit
+    TimedOut(Duration),
+}
+
+impl PartResult {
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            PartResult::Answer(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<String> {
+        match self {
+            PartResult::Failed(e) => Some(e.clone()),
+            PartResult::TimedOut(limit) => {
+                Some(format!("timed out after {}", format_duration(*limit)))
+            }
+            _ => None,
+        }
+    }
+
+    fn new<T: Answer>(result: Result<T, Abort>) -> Self {
+        match result.map(Answer::into_answer) {
+            Ok(Ok(answer)) => PartResult::Answer(answer),
+            Ok(Err(e)) => PartResult::Failed(format!("{e:#}")),
+            Err(Abort::Failed(e)) => PartResult::Failed(e),
+            Err(Abort::TimedOut(limit)) => PartResult::TimedOut(limit),
+        }
+    }
+
+    fn run<T, F>(enabled: bool, timeout: Option<Duration>, f: F) -> (Self, Duration, AllocStats)
+    where
+        T: Answer + Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        if !enabled {
+            return (PartResult::Skipped, Duration::ZERO, AllocStats::default());
+        }
+
+        let (result, duration, allocs) = measured(timeout, f);
+        (Self::new(result), duration, allocs)
+    }
+}
+
+/// Why a measured run produced no value.
+#[derive(Clone)]
+enum Abort {
+    Failed(String),
+    TimedOut(Duration),
+}
+
+/// Runs `f` measuring its duration and allocations, on its own thread when it has a time limit
+/// so that it can be abandoned.
+fn measured<T, F>(timeout: Option<Duration>, f: F) -> (Result<T, Abort>, Duration, AllocStats)
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let timed = move || {
+        let measure = Measure::start();
+        let start = Instant::now();
+        let result = catch_panic(f);
+        let duration = start.elapsed();
+        (result, duration, measure.finish())
+    };
+
+    let (result, duration, allocs) = match timeout {
+        None => timed(),
+        Some(limit) => {
+            let (tx, rx) = mpsc::channel();
+            let day = trace::current_day();
+            let part = progress::new_part();
+            thread::spawn(move || {
+                let _day = day.map(trace::enter);
+                progress::enter_part(part);
+                tx.send(timed())
+            });
+            match rx.recv_timeout(limit) {
+                Ok(r) => r,
+                Err(RecvTimeoutError::Timeout) => {
+                    progress::abandon(part);
+                    return (Err(Abort::TimedOut(limit)), limit, AllocStats::default());
+                }
+                Err(RecvTimeoutError::Disconnected) => (
+                    Err("part thread exited".to_string()),
+                    limit,
+                    AllocStats::default(),
+                ),
+            }
+        }
+    };
+
+    (result.map_err(Abort::Failed), duration, allocs)
+}
+
+/// Sub-phase timings of a combined solve, each lap measuring the time since the previous one.
+pub struct Phases {
+    last: Instant,
+    laps: Vec<(&'static str, Duration)>,
+}
+
+impl Phases {
+    fn new() -> Self {
+        Self {
+            last: Instant::now(),
+            laps: vec![],
+        }
+    }
+
+    /// Ends the current sub-phase, naming it.
+    pub fn lap(&mut self, name: &'static str) {
+        let now = Instant::now();
+        self.laps.push((name, now - self.last));
+        self.last = now;
+    }
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartResult::Answer(s) => f.pad(s),
+            PartResult::Skipped => f.pad("skipped"),
+            PartResult::Failed(_) => f.pad("failed"),
+            PartResult::TimedOut(_) => f.pad("timed out"),
+        }
+    }
+}
+
 pub struct ProblemOutput {
-    pub part1: String,
-    pub part2: String,
+    pub day: u32,
+    /// Name of the input, for named inputs of the day
+    pub input: Option<String>,
+    /// File the input was read from, none for stdin
+    pub input_path: Option<PathBuf>,
+    /// Set when the input could not be read or parsed, both parts are then skipped
+    pub input_error: Option<String>,
+    pub part1: PartResult,
+    pub part2: PartResult,
     pub timings: Timings,
+    /// Allocations of each phase, only counted with the `count-allocs` feature
+    pub allocs: Option<PhaseAllocs>,
+    /// Sub-phase timings when both parts were solved in one pass, their time is then counted in
+    /// part 1
+    pub combined: Option<Vec<(&'static str, Duration)>>,
+}
+
+impl ProblemOutput {
+    /// `Day NN`, followed by the name of the input when it has one.
+    pub fn title(&self) -> String {
+        match &self.input {
+            Some(name) => format!("Day {:02} ({name})", self.day),
+            None => format!("Day {:02}", self.day),
+        }
+    }
+
+    /// Describes everything that went wrong while solving this day.
+    pub fn failures(&self) -> Vec<String> {
+        let input = self.input_error.iter().map(|e| format!("input: {e}"));
+        let parts = [(1, &self.part1), (2, &self.part2)]
+            .into_iter()
+            .filter_map(|(n, p)| p.error().map(|e| format!("part {n}: {e}")));
+
+        input.chain(parts).collect()
+    }
+
+    pub fn failed(&self) -> bool {
+        !self.failures().is_empty()
+    }
+}
+
+fn write_allocs(f: &mut Formatter<'_>, allocs: Option<&AllocStats>) -> std::fmt::Result {
+    match allocs {
+        Some(a) => write!(f, " [{a}]"),
+        None => Ok(()),
+    }
+}
+
+fn write_part(
+    f: &mut Formatter<'_>,
+    n: u8,
+    part: &PartResult,
+    d: Duration,
+    allocs: Option<&AllocStats>,
+) -> std::fmt::Result {
+    match part {
+        PartResult::Skipped => write!(f, "Part {n}: {part}"),
+        PartResult::Failed(_) | PartResult::TimedOut(_) => {
+            write!(
+                f,
+                "Part {n}: {part:<20} ({})",
+                part.error().unwrap_or_default()
+            )
+        }
+        PartResult::Answer(_) => {
+            write!(f, "Part {n}: {part:<20} ({})", format_duration(d))?;
+            write_allocs(f, allocs)
+        }
+    }
 }
 
 impl Display for ProblemOutput {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(
-            f,
-            "Parsing:{:<20} ({})",
-            "",
-            format_duration(self.timings.parse_duration)
-        )?;
-        writeln!(
+        match &self.input_error {
+            Some(e) => writeln!(f, "Parsing:{:<20} ({e})", " failed")?,
+            None => {
+                write!(
+                    f,
+                    "Parsing:{:<20} ({})",
+                    "",
+                    format_duration(self.timings.parse_duration)
+                )?;
+                write_allocs(f, self.allocs.as_ref().map(|a| &a.parse))?;
+                writeln!(f)?
+            }
+        }
+        write_part(
             f,
-            "Part 1: {:<20} ({})",
-            self.part1,
-            format_duration(self.timings.part1_duration)
+            1,
+            &self.part1,
+            self.timings.part1_duration,
+            self.allocs.as_ref().map(|a| &a.part1),
         )?;
-        write!(
-            f,
-            "Part 2: {:<20} ({})",
-            self.part2,
-            format_duration(self.timings.part2_duration)
-        )
+        writeln!(f)?;
+        match (&self.combined, &self.part2) {
+            (Some(_), PartResult::Answer(_)) => {
+                write!(f, "Part 2: {:<20} (solved with part 1)", self.part2)?
+            }
+            _ => write_part(
+                f,
+                2,
+                &self.part2,
+                self.timings.part2_duration,
+                self.allocs.as_ref().map(|a| &a.part2),
+            )?,
+        }
+        for (name, d) in self.combined.iter().flatten() {
+            write!(f, "\n  {name}: {}", format_duration(*d))?;
+        }
+
+        Ok(())
     }
 }
 
-pub trait Solver {
-    type Input;
-    type Output1: Display;
-    type Output2: Display;
+pub trait Solver: Sync + 'static {
+    /// Title of the puzzle, e.g. "Laboratories"
+    const TITLE: &'static str = "";
+    /// Kinds of puzzle and techniques used, e.g. "grid" or "simulation"
+    const TAGS: &'static [&'static str] = &[];
+    /// Whether the solution is complete, unset by the generated template until it is
+    const IMPLEMENTED: bool = true;
+    /// Whether `solve_both` is implemented, so that it is only tried then
+    const COMBINED: bool = false;
+
+    type Input: Send + Sync + 'static;
+    type Output1: Answer + Send + 'static;
+    type Output2: Answer + Send + 'static;
 
     fn parse_input<R: BufRead>(&self, r: R) -> anyhow::Result<Self::Input>;
     fn solve_first(&self, input: &Self::Input) -> Self::Output1;
     fn solve_second(&self, input: &Self::Input) -> Self::Output2;
 
+    /// Computes both answers in one pass, for days whose parts share work, optionally timing
+    /// sub-phases with [`Phases::lap`]. When `COMBINED` is set, it is used instead of
+    /// `solve_first` and `solve_second` when both parts are solved, and the time limit then
+    /// covers both parts.
+    fn solve_both(
+        &self,
+        input: &Self::Input,
+        _phases: &mut Phases,
+    ) -> (Self::Output1, Self::Output2) {
+        (self.solve_first(input), self.solve_second(input))
+    }
+
     fn load_input<P: AsRef<Path>>(&self, p: P) -> anyhow::Result<Self::Input> {
-        let f = File::open(p)?;
+        let p = p.as_ref();
+        let f = File::open(p).with_context(|| format!("unable to open {}", p.display()))?;
         self.parse_input(BufReader::new(f))
     }
 
-    fn solve(&self, day: u32) -> ProblemOutput {
-        let input_file = input_file(day);
+    fn read_input(&self, day: u32, source: &InputSource) -> anyhow::Result<Self::Input> {
+        match source {
+            InputSource::Default => self.load_input(input_file(day)),
+            InputSource::File(p) => self.load_input(p),
+            InputSource::Stdin => self.parse_input(stdin().lock()),
+        }
+    }
+
+    /// Solves a day on each of its inputs, or on the input given in the options.
+    fn solve(&'static self, day: u32, opts: &RunOptions) -> Vec<ProblemOutput> {
+        let InputSource::Default = opts.input else {
+            return vec![self.solve_input(day, None, &opts.input, opts)];
+        };
 
+        opts.day_inputs(day)
+            .into_iter()
+            .map(|i| self.solve_input(day, i.name, &InputSource::File(i.path), opts))
+            .collect()
+    }
+
+    /// Solves a day on a single input, parts may outlive this call when abandoned after a
+    /// timeout, hence the `'static` receiver.
+    fn solve_input(
+        &'static self,
+        day: u32,
+        name: Option<String>,
+        source: &InputSource,
+        opts: &RunOptions,
+    ) -> ProblemOutput {
+        let _day = trace::enter(day);
+        let input_path = match source {
+            InputSource::Default => Some(PathBuf::from(input_file(day))),
+            InputSource::File(p) => Some(p.clone()),
+            InputSource::Stdin => None,
+        };
+
+        let measure = Measure::start();
         let start = Instant::now();
-        let input = self
-            .load_input(input_file)
-            .expect("unable to open input file");
+        let input = catch_panic(|| self.read_input(day, source))
+            .and_then(|r| r.map_err(|e| format!("{e:#}")));
         let parse_duration = start.elapsed();
+        let parse_allocs = measure.finish();
 
-        let start = Instant::now();
-        let s1 = self.solve_first(&input);
-        let part1_duration = start.elapsed();
+        let mut timings = Timings {
+            parse_duration,
+            ..Default::default()
+        };
+        let mut allocs = PhaseAllocs {
+            parse: parse_allocs,
+            ..Default::default()
+        };
+        let mut combined = None;
 
-        let start = Instant::now();
-        let s2 = self.solve_second(&input);
-        let part2_duration = start.elapsed();
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                return ProblemOutput {
+                    day,
+                    input: name,
+                    input_path,
+                    input_error: Some(e),
+                    part1: PartResult::Skipped,
+                    part2: PartResult::Skipped,
+                    timings,
+                    allocs: alloc::enabled().then_some(allocs),
+                    combined,
+                };
+            }
+        };
+
+        let input = Arc::new(input);
+        let both = if Self::COMBINED && opts.parts.first && opts.parts.second {
+            let (result, duration, both_allocs) = measured(opts.timeout, {
+                let input = input.clone();
+                move || {
+                    let mut phases = Phases::new();
+                    let answers = self.solve_both(&input, &mut phases);
+                    (answers, phases.laps)
+                }
+            });
+            timings.part1_duration = duration;
+            allocs.part1 = both_allocs;
+
+            match result {
+                Ok(((answer1, answer2), laps)) => {
+                    combined = Some(laps);
+                    Some((PartResult::new(Ok(answer1)), PartResult::new(Ok(answer2))))
+                }
+                Err(abort) => {
+                    combined = Some(vec![]);
+                    Some((
+                        PartResult::new::<Self::Output1>(Err(abort.clone())),
+                        PartResult::new::<Self::Output2>(Err(abort)),
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+
+        let (part1, part2) = match both {
+            Some(parts) => parts,
+            None => {
+                let (part1, duration, part_allocs) =
+                    PartResult::run(opts.parts.first, opts.timeout, {
+                        let input = input.clone();
+                        move || self.solve_first(&input)
+                    });
+                timings.part1_duration = duration;
+                allocs.part1 = part_allocs;
+
+                let (part2, duration, part_allocs) =
+                    PartResult::run(opts.parts.second, opts.timeout, {
+                        let input = input.clone();
+                        move || self.solve_second(&input)
+                    });
+                timings.part2_duration = duration;
+                allocs.part2 = part_allocs;
+
+                (part1, part2)
+            }
+        };
 
         ProblemOutput {
-            part1: s1.to_string(),
-            part2: s2.to_string(),
-            timings: Timings {
-                parse_duration,
-                part1_duration,
-                part2_duration,
-            },
+            day,
+            input: name,
+            input_path,
+            input_error: None,
+            part1,
+            part2,
+            timings,
+            allocs: alloc::enabled().then_some(allocs),
+            combined,
         }
     }
 }
+
+/// Object-safe view of a [`Solver`], with its input and output types erased, so that the
+/// solvers of all days can be handled uniformly.
+pub trait DynSolver: Sync {
+    fn solve_day(&'static self, day: u32, opts: &RunOptions) -> Vec<ProblemOutput>;
+}
+
+impl<S: Solver> DynSolver for S {
+    fn solve_day(&'static self, day: u32, opts: &RunOptions) -> Vec<ProblemOutput> {
+        self.solve(day, opts)
+    }
+}
+
+/// A solver of the generated registry, with its metadata.
+pub struct Registered {
+    pub day: u32,
+    /// Module implementing the solver, e.g. `day07`
+    pub module: &'static str,
+    pub title: &'static str,
+    pub tags: &'static [&'static str],
+    /// See [`Solver::IMPLEMENTED`]
+    pub implemented: bool,
+    pub solver: &'static dyn DynSolver,
+}
+
+impl Registered {
+    pub fn solve(&self, opts: &RunOptions) -> Vec<ProblemOutput> {
+        let _quiet = QuietPanics::new();
+        self.solver.solve_day(self.day, opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input_name() {
+        assert_eq!(parse_input_name("07.txt"), Some((7, None)));
+        assert_eq!(parse_input_name("07-alice.txt"), Some((7, Some("alice"))));
+        assert_eq!(parse_input_name("12-bob-2.txt"), Some((12, Some("bob-2"))));
+        assert_eq!(parse_input_name("07-.txt"), None);
+        assert_eq!(parse_input_name("7.txt"), None);
+        assert_eq!(parse_input_name("07.answers"), None);
+        assert_eq!(parse_input_name("README.md"), None);
+    }
+
+    struct Sleepy;
+
+    impl Solver for Sleepy {
+        type Input = ();
+        type Output1 = u64;
+        type Output2 = u64;
+
+        fn parse_input<R: BufRead>(&self, _r: R) -> anyhow::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn solve_first(&self, _input: &Self::Input) -> Self::Output1 {
+            thread::sleep(Duration::from_secs(5));
+            1
+        }
+
+        fn solve_second(&self, _input: &Self::Input) -> Self::Output2 {
+            2
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let opts = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let source = InputSource::File("Cargo.toml".into());
+
+        let start = Instant::now();
+        let o = Sleepy.solve_input(1, None, &source, &opts);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(o.part1, PartResult::TimedOut(_)));
+        assert_eq!(o.part2.answer(), Some("2"));
+    }
+}
@@ -0,0 +1,314 @@
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const SUBMISSIONS_FILE: &str = ".aoc/submissions.jsonl";
+
+/// How the server responded to a submitted answer.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answer submitted too recently, the answer was not checked
+    Wait,
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+    /// Unrecognized response, with its text
+    Unknown(String),
+}
+
+impl Outcome {
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait => write!(f, "not checked, submitted too recently"),
+            Outcome::WrongLevel => write!(f, "not checked, part already solved or locked"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {text}"),
+        }
+    }
+}
+
+/// The text of the `<article>` of a response page, without tags.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = true; // starts within the `<article ...>` tag
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// How long to wait before submitting again, e.g. "You have 4m 12s left to wait" or
+/// "please wait one minute before trying again".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((_, rest)) = text.split_once("You have ")
+        && let Some((wait, _)) = rest.split_once(" left to wait")
+    {
+        return humantime::parse_duration(wait).ok();
+    }
+
+    let (_, rest) = text.split_once("please wait ")?;
+    let (wait, _) = rest.split_once(" before trying again")?;
+    let (count, unit) = wait.split_once(' ')?;
+    let count = match count {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    match unit.trim_end_matches('s') {
+        "minute" => Some(Duration::from_secs(count * 60)),
+        "second" => Some(Duration::from_secs(count)),
+        _ => None,
+    }
+}
+
+/// Parses the response page of a submission into its outcome and the time to wait before the
+/// next submission, if any.
+pub fn parse_response(html: &str) -> (Outcome, Option<Duration>) {
+    let text = article_text(html);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Wait
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text.clone())
+    };
+
+    (outcome, parse_wait(&text))
+}
+
+/// A submitted answer, stored as one JSON line of the submissions file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch
+    pub timestamp: u64,
+    /// No answer is accepted before this time, in seconds since the unix epoch
+    pub wait_until: Option<u64>,
+}
+
+impl Submission {
+    pub fn new(day: u32, part: u8, answer: &str, html: &str) -> Self {
+        let (outcome, wait) = parse_response(html);
+        let timestamp = now();
+
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp,
+            wait_until: wait.map(|w| timestamp + w.as_secs()),
+        }
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Why an answer should not be submitted, given the previous submissions.
+pub fn refusal(
+    submissions: &[Submission],
+    day: u32,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Option<String> {
+    if let Some(wait_until) = submissions.iter().filter_map(|s| s.wait_until).max()
+        && wait_until > now
+    {
+        return Some(format!(
+            "wait {}s before submitting again",
+            wait_until - now
+        ));
+    }
+
+    let previous = submissions
+        .iter()
+        .filter(|s| s.day == day && s.part == part)
+        .collect::<Vec<_>>();
+    if let Some(s) = previous.iter().find(|s| s.outcome == Outcome::Correct) {
+        return Some(format!("already solved with {}", s.answer));
+    }
+    if let Some(s) = previous
+        .iter()
+        .find(|s| s.answer == answer && s.outcome.is_rejection())
+    {
+        return Some(format!("{answer} was already submitted: {}", s.outcome));
+    }
+
+    // numeric answers can be compared with the ones known to be too high or too low
+    let value = answer.parse::<i128>().ok()?;
+    previous.iter().find_map(|s| {
+        let bound = s.answer.parse::<i128>().ok()?;
+        match s.outcome {
+            Outcome::TooHigh if value >= bound => {
+                Some(format!("{answer} is too high, {bound} already was"))
+            }
+            Outcome::TooLow if value <= bound => {
+                Some(format!("{answer} is too low, {bound} already was"))
+            }
+            _ => None,
+        }
+    })
+}
+
+pub fn load() -> anyhow::Result<Vec<Submission>> {
+    let content = match fs::read_to_string(SUBMISSIONS_FILE) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e).with_context(|| format!("unable to read {SUBMISSIONS_FILE}")),
+    };
+
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .map(|(i, l)| {
+            serde_json::from_str(l)
+                .with_context(|| format!("invalid entry on line {} of {SUBMISSIONS_FILE}", i + 1))
+        })
+        .collect()
+}
+
+pub fn append(submission: &Submission) -> anyhow::Result<()> {
+    if let Some(dir) = Path::new(SUBMISSIONS_FILE).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(SUBMISSIONS_FILE)
+        .with_context(|| format!("unable to open {SUBMISSIONS_FILE}"))?;
+    writeln!(f, "{}", serde_json::to_string(submission)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><main><article><p>{article}</p></article></main></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let (outcome, wait) = parse_response(&page(
+            "That's the right answer! You are <em>one gold star</em> closer.",
+        ));
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(wait, None);
+
+        let (outcome, wait) = parse_response(&page(
+            "That's not the right answer; your answer is too high. If you're stuck, \
+             please wait one minute before trying again. <a href=\"/2025/day/1\">[Return]</a>",
+        ));
+        assert_eq!(outcome, Outcome::TooHigh);
+        assert_eq!(wait, Some(Duration::from_secs(60)));
+
+        let (outcome, wait) = parse_response(&page(
+            "That's not the right answer. Because you have guessed incorrectly 4 times on this \
+             puzzle, please wait 5 minutes before trying again.",
+        ));
+        assert_eq!(outcome, Outcome::Wrong);
+        assert_eq!(wait, Some(Duration::from_secs(300)));
+
+        let (outcome, wait) = parse_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 4m 12s left to wait.",
+        ));
+        assert_eq!(outcome, Outcome::Wait);
+        assert_eq!(wait, Some(Duration::from_secs(252)));
+
+        let (outcome, _) = parse_response(&page(
+            "You don't seem to be solving the right level. Did you already complete it?",
+        ));
+        assert_eq!(outcome, Outcome::WrongLevel);
+
+        let (outcome, _) = parse_response("<p>Something   else</p>");
+        assert_eq!(outcome, Outcome::Unknown("Something else".to_string()));
+    }
+
+    fn submission(part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: 0,
+            wait_until: None,
+        }
+    }
+
+    #[test]
+    fn test_refusal() {
+        let subs = [
+            submission(1, "100", Outcome::TooHigh),
+            submission(1, "10", Outcome::TooLow),
+            submission(1, "abc", Outcome::Wrong),
+            submission(2, "7", Outcome::Correct),
+        ];
+
+        assert_eq!(refusal(&subs, 1, 1, "50", 0), None);
+        assert!(refusal(&subs, 1, 1, "100", 0).is_some());
+        assert!(refusal(&subs, 1, 1, "150", 0).is_some());
+        assert!(refusal(&subs, 1, 1, "5", 0).is_some());
+        assert!(refusal(&subs, 1, 1, "abc", 0).is_some());
+        assert!(refusal(&subs, 1, 2, "8", 0).is_some());
+        assert_eq!(refusal(&subs, 2, 1, "100", 0), None);
+
+        let mut waiting = submission(1, "60", Outcome::Wait);
+        waiting.wait_until = Some(30);
+        assert!(refusal(&[waiting.clone()], 1, 1, "50", 10).is_some());
+        assert_eq!(refusal(&[waiting], 1, 1, "50", 30), None);
+    }
+}
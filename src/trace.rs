@@ -0,0 +1,105 @@
+use clap::ValueEnum;
+use std::cell::Cell;
+use std::fmt::Arguments;
+use std::sync::OnceLock;
+
+/// Verbosity of a diagnostic message, solvers are silent unless enabled by the runner.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+pub enum Level {
+    #[default]
+    Debug,
+    Trace,
+}
+
+struct Config {
+    level: Level,
+    days: Vec<u32>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+thread_local! {
+    /// The day being solved on this thread
+    static CURRENT_DAY: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// Prints the messages up to `level` of the given days, for the rest of the process.
+pub fn init(level: Level, days: Vec<u32>) {
+    let _ = CONFIG.set(Config { level, days });
+}
+
+/// Marks the current thread as solving `day` until the returned guard is dropped.
+pub fn enter(day: u32) -> DayGuard {
+    DayGuard(CURRENT_DAY.replace(Some(day)))
+}
+
+pub fn current_day() -> Option<u32> {
+    CURRENT_DAY.get()
+}
+
+pub struct DayGuard(Option<u32>);
+
+impl Drop for DayGuard {
+    fn drop(&mut self) {
+        CURRENT_DAY.set(self.0);
+    }
+}
+
+/// Handle tagging messages with the day being solved when it was taken, for code running on
+/// other threads than the one solving the day, such as rayon closures, where the current day is
+/// unknown or even the one of another day.
+#[derive(Clone, Copy, Debug)]
+pub struct Tracer {
+    day: Option<u32>,
+}
+
+/// A handle for the day being solved on the current thread.
+pub fn tracer() -> Tracer {
+    Tracer { day: current_day() }
+}
+
+impl Tracer {
+    pub fn enabled(&self, level: Level) -> bool {
+        match (CONFIG.get(), self.day) {
+            (Some(config), Some(day)) => level <= config.level && config.days.contains(&day),
+            _ => false,
+        }
+    }
+
+    /// Prints a message on stderr, keeping stdout for the results.
+    pub fn emit(&self, level: Level, args: Arguments<'_>) {
+        let day = self.day.unwrap_or_default();
+        let level = format!("{level:?}").to_lowercase();
+        eprintln!("[day {day:02} {level}] {args}");
+    }
+}
+
+/// Prints a diagnostic message when debugging is enabled for the current day, or for the day of
+/// a [`Tracer`] given first: `debug!(tracer; "...")`.
+macro_rules! debug {
+    ($tracer:expr; $($arg:tt)*) => {
+        let tracer = $tracer;
+        if tracer.enabled($crate::trace::Level::Debug) {
+            tracer.emit($crate::trace::Level::Debug, format_args!($($arg)*));
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::trace::debug!($crate::trace::tracer(); $($arg)*)
+    };
+}
+
+/// Prints a detailed diagnostic message when tracing is enabled for the current day, or for the
+/// day of a [`Tracer`] given first: `trace!(tracer; "...")`.
+macro_rules! trace {
+    ($tracer:expr; $($arg:tt)*) => {
+        let tracer = $tracer;
+        if tracer.enabled($crate::trace::Level::Trace) {
+            tracer.emit($crate::trace::Level::Trace, format_args!($($arg)*));
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::trace::trace!($crate::trace::tracer(); $($arg)*)
+    };
+}
+
+pub(crate) use {debug, trace};
@@ -0,0 +1,68 @@
+use crate::solutions;
+use crate::solver::{InputSource, RunOptions, day_inputs, example_inputs};
+use anyhow::bail;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The files whose changes trigger a new run: the given input, or all the inputs and examples of
+/// the day, including the missing input to solve for its creation to be noticed.
+fn watched_files(day: u32, opts: &RunOptions) -> Vec<PathBuf> {
+    match &opts.input {
+        InputSource::File(p) => vec![p.clone()],
+        // the examples are watched too, being edited while working on the real input
+        _ => opts
+            .day_inputs(day)
+            .into_iter()
+            .chain(example_inputs(day))
+            .chain(day_inputs(day))
+            .map(|i| i.path)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+    }
+}
+
+fn snapshot(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|f| (f.clone(), fs::metadata(f).and_then(|m| m.modified()).ok()))
+        .collect()
+}
+
+/// Solves a day each time one of its input files changes, until interrupted.
+pub fn watch(day: u32, opts: &RunOptions) -> anyhow::Result<()> {
+    if let InputSource::Stdin = opts.input {
+        bail!("stdin can't be watched");
+    }
+
+    let mut last = None;
+    loop {
+        let files = watched_files(day, opts);
+        let current = snapshot(&files);
+        if last.as_ref() != Some(&current) {
+            // clear the screen and move the cursor to the top left corner
+            print!("\x1b[2J\x1b[H");
+            println!(
+                "=== Day {day} === (watching {} file(s), Ctrl-C to stop)",
+                files.len()
+            );
+            let Some(outputs) = solutions::find(day).map(|s| s.solve(opts)) else {
+                bail!("Day {day} not found");
+            };
+            for o in outputs.iter() {
+                if outputs.len() > 1 {
+                    println!("--- {} ---", o.title());
+                }
+                println!("{o}");
+            }
+            last = Some(current);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}